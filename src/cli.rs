@@ -0,0 +1,58 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+use crate::{scaffold, serve, site::Site};
+
+#[derive(Parser)]
+#[command(name = "pages", about = "Build static websites with Rust")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create a new pages project
+    New { name: String },
+    /// Add a page, e.g. `music` for a static route or `music/song_` for a dynamic one
+    Add { route: String },
+    /// Build the site
+    Build {
+        /// Add the live reload script to every page, used by `serve` when rebuilding
+        #[arg(long)]
+        dev: bool,
+        /// Write the site here instead of the site's own output directory, e.g. `dist`
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+    },
+    /// Build the site, serve it locally and rebuild and reload it when anything changes
+    Serve {
+        #[arg(short, long, default_value_t = 8000)]
+        port: u16,
+    },
+}
+
+// `build` is the site's own function that turns its data into a Site, everything else is generic
+pub fn run(build: fn() -> Site) {
+    match Cli::parse().command {
+        Command::New { name } => scaffold::new_project(&name),
+        Command::Add { route } => scaffold::add_route(&route),
+        Command::Build { dev, out } => {
+            let mut site = build();
+            if let Some(out) = out {
+                site.set_output_dir(out);
+            }
+            if dev {
+                site.publish_dev();
+            } else {
+                site.publish();
+            }
+        }
+        Command::Serve { port } => {
+            let site = build();
+            site.publish_dev();
+            serve::serve(&site.out_dir, &site.base_path, port);
+        }
+    }
+}
@@ -126,6 +126,15 @@ pub mod contentful {
     #[derive(Debug, Clone, Deserialize)]
     pub struct Item {
         pub fields: Fields,
+        pub sys: ItemSys,
+    }
+
+    #[derive(Debug, Clone, Deserialize)]
+    pub struct ItemSys {
+        #[serde(rename = "createdAt")]
+        pub created_at: Option<String>,
+        #[serde(rename = "updatedAt")]
+        pub updated_at: Option<String>,
     }
 
     #[derive(Debug, Clone, Deserialize)]
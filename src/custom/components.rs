@@ -1,12 +1,12 @@
-use super::api::get_testimonials_data;
-use super::datatypes::{Home, Projects, Skills, Testimonials, View};
+use super::datatypes::{Home, Project, Projects, Skill, Skills, Testimonials, View};
+use super::routes;
 use super::theme::*;
 use super::utility::*;
-use crate::custom::api::{get_past_projects_data, get_skills_data};
 use crate::ui::*;
 use chrono::prelude::*;
 use chrono::{Datelike, Timelike};
 use chrono_tz::Australia::Sydney;
+use url::Url;
 
 pub fn home_page_template(content: Element) -> Element {
     column()
@@ -36,9 +36,12 @@ pub fn footer() -> Element {
     let month = sydney_now.format("%B").to_string();
     let year = sydney_now.year();
 
+    // a <time> so the date can be read by machines too, the markup is made from the clock alone so it's safe as it is
     row()
-        .push(text(&format!(
-            "Last Updated on {}, {} of {}, {} at {}:{:02}:{:02}{}",
+        .with_tag(Landmark::Footer)
+        .push(raw_html(&format!(
+            "<time datetime=\"{}\">Last Updated on {}, {} of {}, {} at {}:{:02}:{:02}{}</time>",
+            sydney_now.to_rfc3339(),
             day_name,
             ordinal(day),
             month,
@@ -48,36 +51,51 @@ pub fn footer() -> Element {
             sydney_now.second(),
             am_pm,
         )))
-        .add_styles(&[Style::FontSize(Unit::Px(14)), Style::Padding(Unit::Px(5))])
+        .add_styles(&[
+            Style::FontSize(Unit::FontSize("s")),
+            Style::Padding(Unit::Px(5)),
+        ])
 }
 
-pub fn header_link(label: &str, target: &str) -> Element {
+pub fn header_link(label: &str, target: impl ToString) -> Element {
     link(text(label), target)
         .add_styles(&[
             Style::Padding(Unit::Px(15)),
             Style::Height(Unit::Percent(100.0)),
             Style::TextColor(colors::DARK_MEDIUM_GRAY),
             Style::NoUnderline,
+            Style::Transition(vec![Transition::new("color", 200)]),
         ])
         .add_hover_style(Style::TextColor(colors::PLATINUM))
+        .add_state_style(State::FocusVisible, Style::TextColor(colors::PLATINUM))
 }
 
 pub fn introduction() -> Element {
-    const INTRO_TEXT:&'static str = "My name is Sashin, and I help ambitious and creative individuals and organisations design and build their dream websites.\n\nI work directly with clients to bring their vision to life, getting to know them, their mission and brand, and create websites that reflect them.";
+    const WHO: &str = "My name is Sashin, and I help ambitious and creative individuals and organisations design and build their dream websites.";
+    const HOW: &str = "I work directly with clients to bring their vision to life, getting to know them, their mission and brand, and create websites that reflect them.";
     column()
         .push(
             row()
-                .push(text(INTRO_TEXT).add_styles(&[
-                    Style::Width(Unit::Percent(100.0)),
-                    Style::LineHeight(Unit::Percent(150.0)),
-                ]))
+                .push(
+                    paragraphs(vec![paragraph(WHO), paragraph(HOW)])
+                        .add_styles(&[
+                            Style::Width(Unit::Percent(100.0)),
+                            Style::LineHeight(Unit::Percent(150.0)),
+                        ])
+                        .add_paragraph_styles(&[Style::MarginEach(Sides::new(
+                            Unit::Px(0),
+                            Unit::Px(15),
+                            Unit::Px(0),
+                            Unit::Px(0),
+                        ))]),
+                )
                 .add_styles(&[Style::Width(Unit::Percent(100.0))]),
         )
         .add_styles(&[
             Style::Width(Unit::Percent(100.0)),
             Style::Padding(Unit::Px(20)),
             Style::FontWeight(FontWeight::Light),
-            Style::FontSize(Unit::Px(18)),
+            Style::FontSize(Unit::FontSize("m")),
             Style::TextAlign(TextAlign::Justify),
         ])
 }
@@ -87,13 +105,15 @@ pub fn page_title(title: &str) -> Element {
         .add_styles(&[
             Style::FontWeight(FontWeight::Light),
             Style::TextColor(colors::MIDDLE_GREEN),
-            Style::FontSize(Unit::Px(36)),
+            Style::FontSize(Unit::FontSize("xl")),
+            Style::Transition(vec![Transition::new("color", 200)]),
         ])
         .add_hover_style(Style::TextColor(colors::TURQUOISE_GREEN))
 }
 
 pub fn page_header() -> Element {
     row()
+        .with_tag(Landmark::Header)
         .add_styles(&[
             Style::JustifyContent(JustifyContent::SpaceBetween),
             Style::Width(Unit::Percent(100.0)),
@@ -106,33 +126,52 @@ pub fn page_header() -> Element {
             Style::Height(Unit::Percent(100.0)),
             Style::FontSize(Unit::Px(13)),
         ])
-        .push(link(page_title("Sashin Dev"), "https://sashin.dev").add_style(Style::NoUnderline))
+        .add_styles_at(Breakpoint::Mobile, &[Style::Column])
+        .push(link(page_title("Sashin Dev"), routes::HOME).add_style(Style::NoUnderline))
         .push(
-            row()
-                .push(header_link("Past Work", "/past-work"))
-                .push(header_link("Skills", "/skills"))
-                .push(header_link("Testimonials", "/testimonials"))
-                .push(header_link("Writing", "/writing"))
-                .push(header_link("Now", "/now")),
+            row().with_tag(Landmark::Nav).push(
+                list()
+                    .add_styles(&[
+                        Style::Row,
+                        Style::ListMarker(ListMarker::None),
+                        Style::Margin(Unit::Px(0)),
+                        Style::Padding(Unit::Px(0)),
+                    ])
+                    .push(header_link("Past Work", routes::PAST_PROJECTS))
+                    .push(header_link("Skills", "skills"))
+                    .push(header_link("Testimonials", routes::TESTIMONIALS))
+                    .push(header_link("Writing", "writing"))
+                    .push(header_link("Now", "now")),
+            ),
         )
 }
 
+// darkens the bottom of a background image so the text over it can be read
+pub fn bottom_shade() -> Background {
+    Background::linear(
+        180.0,
+        vec![
+            ColorStop::at(colors::EERIE_BLACK_DARKER_CLEAR, Unit::Percent(40.0)),
+            ColorStop::new(colors::EERIE_BLACK_DARKER_TRANSPARENT),
+        ],
+    )
+}
+
 pub fn banner() -> Element {
     column()
         .add_styles(&[
-            Style::BackgroundImage(Image {
-                src: "'assets/images/banner.jpg'".to_string(),
-                alt: "banner-image".to_string(),
-            }),
+            Style::BackgroundImage(vec![
+                bottom_shade(),
+                Background::url("assets/images/banner.jpg"),
+            ]),
             Style::Width(Unit::Percent(100.0)),
-            Style::Height(Unit::Px(300)),
+            Style::Height(Unit::clamp(Unit::Px(200), Unit::Dvh(40.0), Unit::Px(300))),
             Style::JustifyContent(JustifyContent::End),
         ])
         .push(
             row()
                 .add_styles(&[
                     Style::Width(Unit::Percent(100.0)),
-                    Style::BackgroundColor(colors::EERIE_BLACK_DARKER_TRANSPARENT),
                     Style::PaddingEach(Sides::new(
                         Unit::Px(20),
                         Unit::Px(20),
@@ -142,7 +181,11 @@ pub fn banner() -> Element {
                 ])
                 .push(
                     text("Crafting better software for creators and innovators").add_styles(&[
-                        Style::FontSize(Unit::Px(30)),
+                        Style::FontSize(Unit::clamp(
+                            Unit::FontSize("m"),
+                            Unit::Rem(1.0) + Unit::Vw(2.0),
+                            Unit::FontSize("l"),
+                        )),
                         Style::FontWeight(FontWeight::ExtraLight),
                         Style::TextAlign(TextAlign::Center),
                         Style::Width(Unit::Percent(100.0)),
@@ -155,24 +198,92 @@ pub fn skills_bar(skills: &Skills) -> Element {
     skills
         .0
         .iter()
-        .fold(row(), |mut skills_bar, skill| {
-            skills_bar.push(
-                image(&skill.thumbnail.src.to_string(), &skill.thumbnail.alt).add_styles(&[
-                    Style::Height(Unit::Px(50)),
-                    Style::Width(Unit::Px(50)),
-                    Style::Padding(Unit::Px(5)),
-                ]),
-            )
-        })
+        .fold(
+            grid(vec![Track::auto_fit(Track::minmax(
+                Track::Length(Unit::Px(60)),
+                Track::Fr(1.0),
+            ))]),
+            |mut skills_bar, skill| {
+                skills_bar.push(
+                    image(&skill.thumbnail.src.to_string(), &skill.thumbnail.alt).add_styles(&[
+                        Style::Height(Unit::Px(50)),
+                        Style::Width(Unit::Px(50)),
+                        Style::Padding(Unit::Px(5)),
+                        Style::ObjectFit(ObjectFit::Contain),
+                    ]),
+                )
+            },
+        )
         .add_styles(&[
             Style::Padding(Unit::Px(5)),
             Style::Rounded(Unit::Px(10)),
             Style::BackgroundColor(colors::EERIE_BLACK_LIGHTER),
             Style::Width(Unit::Percent(100.0)),
-            Style::JustifyContent(JustifyContent::SpaceEvenly),
+            Style::Gap(Unit::Px(5)),
+            Style::JustifyItems(AlignItems::Center),
         ])
 }
 
+// a project's own page, its card followed by where to find it and what it was built with
+pub fn project_view(project: &Project) -> Element {
+    let links = [
+        ("Website", project.website_url()),
+        ("Source code", project.github_url()),
+    ]
+    .into_iter()
+    .filter_map(|(label, url)| Some((label, url?)))
+    .collect::<Vec<(&str, &Url)>>();
+    let mut view = column()
+        .add_styles(&[
+            Style::Width(Unit::Percent(100.0)),
+            Style::Gap(Unit::Space("m")),
+        ])
+        .push(project.view());
+    if !links.is_empty() {
+        view = view.push(project_links(&links));
+    }
+    view.push(skills_table(project.skills()))
+}
+
+// each link is a term naming it and a definition holding it
+fn project_links(links: &[(&str, &Url)]) -> Element {
+    links
+        .iter()
+        .fold(description_list(), |mut list, (label, url)| {
+            list.push(term(label))
+                .push(definition().push(link(text(url.as_str()), url)))
+        })
+        .add_styles(&[
+            Style::Margin(Unit::Px(0)),
+            Style::Padding(Unit::Space("m")),
+            Style::Rounded(Unit::Radius("m")),
+            Style::BackgroundColor(colors::EERIE_BLACK_LIGHTER),
+        ])
+}
+
+// the skills a project used, named in the first column so screen readers read each row by its skill
+pub fn skills_table(skills: &[Skill]) -> Element {
+    table_of(
+        skills,
+        &[
+            TableColumn::new("Skill", |skill: &Skill| {
+                header_cell(Scope::Row)
+                    .push(text(&skill.name))
+                    .add_style(Style::TextAlign(TextAlign::Left))
+            }),
+            TableColumn::new("What it is", |skill: &Skill| text(&skill.description)),
+        ],
+    )
+    .with_caption(text("Built with"))
+    .add_styles(&[
+        Style::Width(Unit::Percent(100.0)),
+        Style::Padding(Unit::Space("m")),
+        Style::Rounded(Unit::Radius("m")),
+        Style::BackgroundColor(colors::EERIE_BLACK_LIGHTER),
+        Style::TextAlign(TextAlign::Left),
+    ])
+}
+
 pub fn projects_view(projects: &Projects) -> Element {
     row()
         .add_styles(&[Style::Width(Unit::Percent(100.0))])
@@ -229,6 +340,7 @@ pub fn testimonials_view(testimonials: &Testimonials) -> Element {
 
 pub fn content(model: &Home) -> Element {
     column()
+        .with_tag(Landmark::Main)
         .add_styles(&[
             Style::Width(Unit::Px(768)),
             Style::Center,
@@ -236,12 +348,20 @@ pub fn content(model: &Home) -> Element {
             Style::RoundedEach(Corners::new(
                 Unit::Px(0),
                 Unit::Px(0),
-                Unit::Px(10),
-                Unit::Px(10),
+                Unit::Radius("m"),
+                Unit::Radius("m"),
             )),
-            Style::Padding(Unit::Px(15)),
+            Style::Padding(Unit::Space("m")),
             Style::JustifyContent(JustifyContent::Start),
         ])
+        .add_styles_at(Breakpoint::Tablet, &[Style::Width(Unit::Percent(100.0))])
+        .add_styles_at(
+            Breakpoint::Mobile,
+            &[
+                Style::Width(Unit::Percent(100.0)),
+                Style::Padding(Unit::Space("xs")),
+            ],
+        )
         .push(footer())
         .push(introduction())
         .push(skills_bar(&model.skills))
@@ -250,17 +370,29 @@ pub fn content(model: &Home) -> Element {
 }
 
 pub fn page_content() -> Element {
-    column().add_styles(&[
-        Style::Width(Unit::Px(768)),
-        Style::Center,
-        Style::BackgroundColor(colors::EERIE_BLACK),
-        Style::RoundedEach(Corners::new(
-            Unit::Px(10),
-            Unit::Px(10),
-            Unit::Px(10),
-            Unit::Px(10),
-        )),
-        Style::Padding(Unit::Px(15)),
-        Style::JustifyContent(JustifyContent::Start),
-    ])
+    column()
+        .with_tag(Landmark::Main)
+        .add_styles(&[
+            Style::Width(Unit::Px(768)),
+            Style::Center,
+            Style::BackgroundColor(colors::EERIE_BLACK),
+            Style::RoundedEach(Corners::new(
+                Unit::Radius("m"),
+                Unit::Radius("m"),
+                Unit::Radius("m"),
+                Unit::Radius("m"),
+            )),
+            Style::Padding(Unit::Space("m")),
+            Style::JustifyContent(JustifyContent::Start),
+            Style::Animation(fade_in().animation(400).easing(Easing::EaseOut)),
+        ])
+        .add_styles_at(Breakpoint::Tablet, &[Style::Width(Unit::Percent(100.0))])
+        .add_styles_at(
+            Breakpoint::Mobile,
+            &[
+                Style::Width(Unit::Percent(100.0)),
+                Style::Padding(Unit::Space("xs")),
+                Style::Rounded(Unit::Px(0)),
+            ],
+        )
 }
@@ -5,15 +5,20 @@ use super::{
         contentful::{AssetData, Item, Items},
         get_asset_by_id, get_person_data_by_id, get_skill_by_id, get_testimonial_by_id,
     },
+    routes,
     theme::*,
 };
+use crate::{feed::FeedItem, route::Slug};
+use chrono::{DateTime, Utc};
 use url::Url;
 #[derive(Debug, Clone)]
 pub enum PageData {
     None,
     Home(Home),
     Projects(Projects),
+    Project(Box<Project>),
     Testimonials(Testimonials),
+    Testimonial(Box<Testimonial>),
 }
 
 pub trait View {
@@ -60,6 +65,17 @@ impl Testimonials {
             .collect::<Vec<Testimonial>>();
         Self(testimonials)
     }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Testimonial> {
+        self.0.iter()
+    }
+
+    pub fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.0
+            .iter()
+            .filter_map(|testimonial| testimonial.updated_at)
+            .max()
+    }
 }
 
 impl View for Testimonials {
@@ -76,11 +92,22 @@ pub struct Testimonial {
     author: Person,
     text: String,
     slug: String,
+    updated_at: Option<DateTime<Utc>>,
 }
 
 impl Testimonial {
+    // the name of the person who gave it
+    pub fn author_name(&self) -> &str {
+        &self.author.name
+    }
+
+    pub fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+
     pub fn from_item(access_token: &str, space_id: &str, item: Item) -> Result<Self, ParseError> {
         Ok(Self {
+            updated_at: updated_at(&item),
             text: item.fields.text.expect("Failed to get testimonial's text"),
             slug: item.fields.slug.expect("Failed to get testimonial's slug"),
             author: Person::from_item(
@@ -107,6 +134,12 @@ impl Testimonial {
     }
 }
 
+impl Slug for Testimonial {
+    fn slug(&self) -> &str {
+        &self.slug
+    }
+}
+
 impl View for Testimonial {
     fn view(&self) -> Element {
         row()
@@ -200,7 +233,16 @@ impl View for Testimonial {
                                         Style::TextAlign(TextAlign::Left),
                                         Style::JustifyContent(JustifyContent::Start),
                                     ])
-                                    .push(text(&self.author.name)),
+                                    .push(
+                                        link(
+                                            text(&self.author.name),
+                                            routes::testimonial(&self.slug),
+                                        )
+                                        .add_styles(&[
+                                            Style::TextColor(colors::DARK_MEDIUM_GRAY),
+                                            Style::NoUnderline,
+                                        ]),
+                                    ),
                             )
                             .push(
                                 row()
@@ -223,6 +265,23 @@ impl View for Testimonial {
     }
 }
 
+// contentful's sys.updatedAt, which becomes the page's lastmod in the sitemap
+fn updated_at(item: &Item) -> Option<DateTime<Utc>> {
+    item.sys.updated_at.as_ref().map(|updated_at| {
+        DateTime::parse_from_rfc3339(updated_at)
+            .expect("Failed to parse contentful's updatedAt as a date")
+            .with_timezone(&Utc)
+    })
+}
+
+fn created_at(item: &Item) -> Option<DateTime<Utc>> {
+    item.sys.created_at.as_ref().map(|created_at| {
+        DateTime::parse_from_rfc3339(created_at)
+            .expect("Failed to parse contentful's createdAt as a date")
+            .with_timezone(&Utc)
+    })
+}
+
 #[derive(Debug, Clone)]
 pub struct Image {
     pub title: String,
@@ -316,14 +375,29 @@ impl Projects {
             .collect::<Vec<Project>>();
         Self(items)
     }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Project> {
+        self.0.iter()
+    }
+
+    pub fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.0.iter().filter_map(|project| project.updated_at).max()
+    }
 }
 
 impl View for Projects {
     fn view(&self) -> Element {
         self.0
             .iter()
-            .fold(column(), |mut output, project| output.push(project.view()))
-            .add_style(Style::Width(Unit::Percent(100.0)))
+            .fold(ordered_list(), |mut output, project| {
+                output.push(project.view())
+            })
+            .add_styles(&[
+                Style::Width(Unit::Percent(100.0)),
+                Style::ListMarker(ListMarker::None),
+                Style::Margin(Unit::Px(0)),
+                Style::Padding(Unit::Px(0)),
+            ])
     }
 }
 
@@ -338,11 +412,43 @@ pub struct Project {
     website_url: Option<Url>,
     skills: Vec<Skill>,
     slug: String,
+    published: DateTime<Utc>,
+    updated_at: Option<DateTime<Utc>>,
 }
 
 impl Project {
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn screenshot(&self) -> &Image {
+        &self.screenshot
+    }
+
+    pub fn skills(&self) -> &[Skill] {
+        &self.skills
+    }
+
+    pub fn website_url(&self) -> Option<&Url> {
+        self.website_url.as_ref()
+    }
+
+    pub fn github_url(&self) -> Option<&Url> {
+        self.github_url.as_ref()
+    }
+
+    pub fn updated_at(&self) -> Option<DateTime<Utc>> {
+        self.updated_at
+    }
+
     pub fn from_item(access_token: &str, space_id: &str, item: Item) -> Result<Self, ParseError> {
         Ok(Self {
+            published: created_at(&item).expect("Failed to get project's createdAt"),
+            updated_at: updated_at(&item),
             title: item.fields.title.expect("Failed to get project title"),
             screenshot: Image::from_asset_data(
                 get_asset_by_id(
@@ -408,6 +514,31 @@ impl Project {
     }
 }
 
+impl Slug for Project {
+    fn slug(&self) -> &str {
+        &self.slug
+    }
+}
+
+// the past projects feed, each entry is dated from when the project was first published
+impl FeedItem for Project {
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    fn date(&self) -> DateTime<Utc> {
+        self.published
+    }
+
+    fn summary(&self) -> &str {
+        &self.description
+    }
+
+    fn content(&self) -> &str {
+        &self.about
+    }
+}
+
 impl View for Project {
     fn view(&self) -> Element {
         const TITLE_BAR_HEIGHT: Unit = Unit::Px(60);
@@ -431,15 +562,25 @@ impl View for Project {
                         Style::AlignItems(ui::AlignItems::Center),
                         Style::JustifyContent(ui::JustifyContent::Center),
                     ])
-                    .push(heading(HeadingLevel::H3, &self.title).add_styles(&[
-                        Style::FontSize(Unit::Px(25)),
-                        Style::FontWeight(FontWeight::Light),
-                        Style::Padding(Unit::Px(0)),
-                        Style::Width(Unit::Percent(100.0)),
-                        Style::Height(Unit::Percent(100.0)),
-                        Style::LineHeight(TITLE_BAR_HEIGHT),
-                        Style::TextAlign(ui::TextAlign::Center),
-                    ])),
+                    .push(
+                        link(
+                            heading(HeadingLevel::H3, &self.title).add_styles(&[
+                                Style::FontSize(Unit::Px(25)),
+                                Style::FontWeight(FontWeight::Light),
+                                Style::Padding(Unit::Px(0)),
+                                Style::Width(Unit::Percent(100.0)),
+                                Style::Height(Unit::Percent(100.0)),
+                                Style::LineHeight(TITLE_BAR_HEIGHT),
+                                Style::TextAlign(ui::TextAlign::Center),
+                            ]),
+                            routes::project(&self.slug),
+                        )
+                        .add_styles(&[
+                            Style::Width(Unit::Percent(100.0)),
+                            Style::TextColor(colors::DARK_MEDIUM_GRAY),
+                            Style::NoUnderline,
+                        ]),
+                    ),
             )
             .push(
                 row()
@@ -449,10 +590,10 @@ impl View for Project {
                             .add_styles(&[
                                 Style::Width(Unit::Percent(100.0)),
                                 Style::Height(Unit::Px(500)),
-                                Style::BackgroundImage(crate::ui::Image {
-                                    src: self.screenshot.src.to_string(),
-                                    alt: self.screenshot.alt.clone(),
-                                }),
+                                Style::BackgroundImage(vec![
+                                    bottom_shade(),
+                                    Background::url(self.screenshot.src.as_str()),
+                                ]),
                                 Style::BackgroundSize(BackgroundSize::Cover),
                                 Style::AlignItems(ui::AlignItems::Center),
                                 Style::JustifyContent(ui::JustifyContent::End),
@@ -463,9 +604,6 @@ impl View for Project {
                                     .add_styles(&[
                                         Style::Width(Unit::Percent(100.0)),
                                         Style::TextAlign(ui::TextAlign::Center),
-                                        Style::BackgroundColor(
-                                            colors::EERIE_BLACK_DARKER_TRANSPARENT,
-                                        ),
                                         Style::RoundedEach(Corners::new(
                                             Unit::Px(0),
                                             Unit::Px(0),
@@ -2,5 +2,6 @@ pub mod api;
 pub mod components;
 pub mod datatypes;
 pub mod pages;
+pub mod routes;
 pub mod theme;
 pub mod utility;
@@ -1,25 +1,56 @@
-use crate::site::Page;
+use crate::{head::Meta, route::Route, site::Page};
 
 use super::{
-    components::page_template,
-    datatypes::{PageData, Projects, Testimonials, View},
+    components::{page_template, project_view},
+    datatypes::{PageData, Project, Projects, Testimonial, Testimonials, View},
+    routes,
 };
 
 pub fn testimonials(testimonials: &Testimonials) -> Page {
     let mut testimonials_page = Page::new(
         "Sashin Dev - Testimonials",
-        ".public/testimonials.html",
+        routes::TESTIMONIALS,
         PageData::Testimonials(testimonials.clone()),
-    );
+    )
+    .with_last_modified(testimonials.updated_at());
     testimonials_page.push(page_template(testimonials.view()));
     testimonials_page
 }
+
+pub fn testimonial(testimonial: &Testimonial, route: Route) -> Page {
+    let mut testimonial_page = Page::new(
+        &format!("Sashin Dev - Testimonial from {}", testimonial.author_name()),
+        route,
+        PageData::Testimonial(Box::new(testimonial.clone())),
+    )
+    .with_last_modified(testimonial.updated_at());
+    testimonial_page.push(page_template(testimonial.view()));
+    testimonial_page
+}
+
 pub fn projects(projects: &Projects) -> Page {
     let mut projects_page = Page::new(
         "Sashin Dev - Past Projects",
-        ".public/past-projects.html",
+        routes::PAST_PROJECTS,
         PageData::Projects(projects.clone()),
-    );
+    )
+    .with_last_modified(projects.updated_at());
     projects_page.push(page_template(projects.view()));
     projects_page
 }
+
+pub fn project(project: &Project, route: Route) -> Page {
+    let mut project_page = Page::new(
+        &format!("Sashin Dev - {}", project.title()),
+        route,
+        PageData::Project(Box::new(project.clone())),
+    )
+    .with_last_modified(project.updated_at())
+    .with_meta(
+        Meta::new()
+            .description(project.description())
+            .image(project.screenshot().src.as_str()),
+    );
+    project_page.push(page_template(project_view(project)));
+    project_page
+}
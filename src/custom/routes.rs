@@ -0,0 +1,16 @@
+use crate::route::Route;
+
+pub const HOME: Route = Route::Home;
+pub const TESTIMONIALS: Route = Route::Static("testimonials");
+pub const PAST_PROJECTS: Route = Route::Static("past-projects");
+
+pub const PROJECT: &str = "projects/:slug";
+pub const TESTIMONIAL: &str = "testimonials/:slug";
+
+pub fn project(slug: &str) -> Route {
+    Route::dynamic(PROJECT, slug)
+}
+
+pub fn testimonial(slug: &str) -> Route {
+    Route::dynamic(TESTIMONIAL, slug)
+}
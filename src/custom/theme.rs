@@ -1,18 +1,99 @@
 pub mod colors {
 
     use crate::Color; // if Color is defined in another module but in the same crate
-    pub const RICH_BLACK: Color = Color::new(3, 3, 3, 1.0);
-    pub const EERIE_BLACK: Color = Color::new(23, 23, 23, 1.0);
-    pub const EERIE_BLACK_LIGHTEST: Color = Color::new(45, 45, 45, 1.0);
-    pub const EERIE_BLACK_LIGHTEST_TRANSPARENT: Color = Color::new(45, 45, 45, 0.9);
-    pub const EERIE_BLACK_LIGHTER: Color = Color::new(36, 36, 36, 1.0);
-    pub const EERIE_BLACK_LIGHTER_TRANSPARENT: Color = Color::new(36, 36, 36, 0.9);
-    pub const EERIE_BLACK_DARKER: Color = Color::new(18, 18, 18, 1.0);
-    pub const EERIE_BLACK_DARKER_TRANSPARENT: Color = Color::new(18, 18, 18, 0.9);
-    pub const CHARLESTON_GREEN: Color = Color::new(44, 44, 44, 1.0);
-    pub const DARK_MEDIUM_GRAY: Color = Color::new(170, 170, 170, 1.0);
-    pub const PLATINUM: Color = Color::new(233, 233, 233, 1.0);
-    pub const MIDDLE_GREEN: Color = Color::new(82, 170, 94, 1.0);
-    pub const TURQUOISE_GREEN: Color = Color::new(160, 208, 167, 1.0);
-    pub const AMARANTH: Color = Color::new(239, 45, 86, 1.0);
+    pub const RICH_BLACK: Color = Color::new(3, 3, 3, 1.0).as_token("rich-black");
+    pub const EERIE_BLACK: Color = Color::new(23, 23, 23, 1.0).as_token("eerie-black");
+    pub const EERIE_BLACK_LIGHTEST: Color =
+        EERIE_BLACK.lighten(0.086).as_token("eerie-black-lightest");
+    pub const EERIE_BLACK_LIGHTEST_TRANSPARENT: Color = EERIE_BLACK_LIGHTEST.with_alpha(0.9);
+    pub const EERIE_BLACK_LIGHTER: Color =
+        EERIE_BLACK.lighten(0.051).as_token("eerie-black-lighter");
+    pub const EERIE_BLACK_LIGHTER_TRANSPARENT: Color = EERIE_BLACK_LIGHTER.with_alpha(0.9);
+    pub const EERIE_BLACK_DARKER: Color = EERIE_BLACK.darken(0.02).as_token("eerie-black-darker");
+    pub const EERIE_BLACK_DARKER_TRANSPARENT: Color = EERIE_BLACK_DARKER.with_alpha(0.9);
+    pub const EERIE_BLACK_DARKER_CLEAR: Color = EERIE_BLACK_DARKER.with_alpha(0.0);
+    pub const CHARLESTON_GREEN: Color = Color::new(44, 44, 44, 1.0).as_token("charleston-green");
+    pub const DARK_MEDIUM_GRAY: Color = Color::new(170, 170, 170, 1.0).as_token("dark-medium-gray");
+    pub const PLATINUM: Color = Color::new(233, 233, 233, 1.0).as_token("platinum");
+    pub const MIDDLE_GREEN: Color = Color::new(82, 170, 94, 1.0).as_token("middle-green");
+    pub const TURQUOISE_GREEN: Color = Color::new(160, 208, 167, 1.0).as_token("turquoise-green");
+    pub const AMARANTH: Color = Color::new(239, 45, 86, 1.0).as_token("amaranth");
+}
+
+// registered on the site in main.rs, every colour above that has a token and the scales the components use
+pub fn site_theme() -> crate::theme::Theme {
+    use crate::Unit::Px;
+    use colors::*;
+    crate::theme::Theme::new()
+        .color(RICH_BLACK)
+        .color(EERIE_BLACK)
+        .color(EERIE_BLACK_LIGHTEST)
+        .color(EERIE_BLACK_LIGHTER)
+        .color(EERIE_BLACK_DARKER)
+        .color(CHARLESTON_GREEN)
+        .color(DARK_MEDIUM_GRAY)
+        .color(PLATINUM)
+        .color(MIDDLE_GREEN)
+        .color(TURQUOISE_GREEN)
+        .color(AMARANTH)
+        .space("xs", Px(5))
+        .space("s", Px(10))
+        .space("m", Px(15))
+        .space("l", Px(20))
+        .font_size("s", Px(14))
+        .font_size("m", Px(18))
+        .font_size("l", Px(30))
+        .font_size("xl", Px(36))
+        .radius("m", Px(10))
+        .breakpoints(BREAKPOINTS)
+}
+
+pub const UBUNTU: crate::font::FontFamily =
+    crate::font::FontFamily::new("Ubuntu").fallbacks(&["system-ui", "sans-serif"]);
+
+// there is no extra light file, so ExtraLight text falls back to light. Light and regular are on every page so they're
+// preloaded
+pub const UBUNTU_FILES: &[crate::font::FontFile] = {
+    use crate::font::FontFile;
+    use crate::FontWeight::*;
+    &[
+        FontFile::new("assets/fonts/Ubuntu-L.ttf", Light).preload(),
+        FontFile::new("assets/fonts/Ubuntu-LI.ttf", Light).italic(),
+        FontFile::new("assets/fonts/Ubuntu-R.ttf", Normal).preload(),
+        FontFile::new("assets/fonts/Ubuntu-RI.ttf", Normal).italic(),
+        FontFile::new("assets/fonts/Ubuntu-M.ttf", Medium),
+        FontFile::new("assets/fonts/Ubuntu-MI.ttf", Medium).italic(),
+        FontFile::new("assets/fonts/Ubuntu-B.ttf", Bold),
+        FontFile::new("assets/fonts/Ubuntu-BI.ttf", Bold).italic(),
+    ]
+};
+
+// every text colour and the backgrounds it is drawn on, checked against WCAG's AA ratio for body text on each build
+pub fn check_contrast() {
+    use colors::*;
+    let pairs = [
+        ("body text", DARK_MEDIUM_GRAY, EERIE_BLACK),
+        ("body text on cards", DARK_MEDIUM_GRAY, EERIE_BLACK_LIGHTER),
+        ("header links", DARK_MEDIUM_GRAY, RICH_BLACK),
+        ("hovered header links", PLATINUM, RICH_BLACK),
+        ("site title", MIDDLE_GREEN, RICH_BLACK),
+        ("hovered site title", TURQUOISE_GREEN, RICH_BLACK),
+    ];
+    pairs.iter().for_each(|(usage, text, background)| {
+        let ratio = text.contrast_ratio(background);
+        assert!(
+            ratio >= 4.5,
+            "The contrast of the {usage} is {ratio:.2}, it needs to be at least 4.5"
+        );
+    });
+}
+
+// the content column is 768px wide, so anything narrower than that with a little room either side is a tablet
+pub const BREAKPOINTS: crate::Breakpoints = crate::Breakpoints::new(480, 800);
+
+// registered on the site in main.rs, used when a page's content first appears
+pub fn fade_in() -> crate::Keyframes {
+    crate::Keyframes::new("fade-in")
+        .at(0.0, &[crate::Style::Opacity(0.0)])
+        .at(100.0, &[crate::Style::Opacity(1.0)])
 }
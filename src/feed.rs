@@ -0,0 +1,258 @@
+use std::{cmp::Reverse, path::PathBuf};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::{
+    html::{escape_attribute, escape_text},
+    route::{under_base_path, Route, Slug},
+    site::Site,
+};
+
+// implemented by items in a collection that is published as a feed, e.g. blog posts
+pub trait FeedItem: Slug {
+    fn title(&self) -> &str;
+    fn date(&self) -> DateTime<Utc>;
+    fn summary(&self) -> &str;
+    // the full html of the item
+    fn content(&self) -> &str;
+}
+
+// An RSS 2.0 and an Atom feed of a collection, written to <path>/rss.xml and <path>/atom.xml. Every page of the site
+// links to it from its head.
+#[derive(Debug, Clone)]
+pub struct Feed {
+    pub title: String,
+    pub description: String,
+    pub path: &'static str,
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    title: String,
+    route: Route,
+    date: DateTime<Utc>,
+    summary: String,
+    content: String,
+}
+
+impl Feed {
+    // each item links to its page on the dynamic route `pattern`, newest first
+    pub fn new<'a, T: FeedItem + 'a>(
+        title: &str,
+        description: &str,
+        path: &'static str,
+        pattern: &'static str,
+        items: impl IntoIterator<Item = &'a T>,
+    ) -> Self {
+        let mut entries = items
+            .into_iter()
+            .map(|item| Entry {
+                title: item.title().to_string(),
+                route: Route::dynamic(pattern, item.slug()),
+                date: item.date(),
+                summary: item.summary().to_string(),
+                content: item.content().to_string(),
+            })
+            .collect::<Vec<Entry>>();
+        entries.sort_by_key(|entry| Reverse(entry.date));
+        Self {
+            title: title.to_string(),
+            description: description.to_string(),
+            path,
+            entries,
+        }
+    }
+
+    // relative to the site's output directory
+    pub fn rss_path(&self) -> PathBuf {
+        PathBuf::from(self.path.trim_matches('/')).join("rss.xml")
+    }
+
+    pub fn atom_path(&self) -> PathBuf {
+        PathBuf::from(self.path.trim_matches('/')).join("atom.xml")
+    }
+
+    // the newest item's date, or the epoch when it's empty, so publishing the same items twice gives the same feed
+    fn updated(&self) -> DateTime<Utc> {
+        self.entries
+            .first()
+            .map_or(DateTime::UNIX_EPOCH, |entry| entry.date)
+    }
+
+    pub fn link_tags(&self, base_href: &str) -> String {
+        format!(
+            "<link rel=\"alternate\" type=\"application/rss+xml\" title=\"{title}\" href=\"{}\">
+    <link rel=\"alternate\" type=\"application/atom+xml\" title=\"{title}\" href=\"{}\">",
+            escape_attribute(&under_base_path(
+                base_href,
+                &self.rss_path().display().to_string()
+            )),
+            escape_attribute(&under_base_path(
+                base_href,
+                &self.atom_path().display().to_string()
+            )),
+            title = escape_attribute(&self.title),
+        )
+    }
+
+    pub fn to_rss(&self, site: &Site) -> String {
+        let items = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let url = escape_text(&absolute_url(site, &entry.route.path()));
+                format!(
+                    "    <item>
+      <title>{}</title>
+      <link>{url}</link>
+      <guid>{url}</guid>
+      <pubDate>{}</pubDate>
+      <description>{}</description>
+      <content:encoded>{}</content:encoded>
+    </item>",
+                    escape_text(&entry.title),
+                    entry.date.to_rfc2822(),
+                    escape_text(&entry.summary),
+                    escape_text(&entry.content),
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\" xmlns:content=\"http://purl.org/rss/1.0/modules/content/\">
+  <channel>
+    <title>{}</title>
+    <link>{}</link>
+    <description>{}</description>
+    <lastBuildDate>{}</lastBuildDate>
+    <atom:link href=\"{}\" rel=\"self\" type=\"application/rss+xml\"/>
+{items}
+  </channel>
+</rss>
+",
+            escape_text(&self.title),
+            escape_text(&absolute_url(site, "")),
+            escape_text(&self.description),
+            self.updated().to_rfc2822(),
+            escape_attribute(&absolute_url(site, &self.rss_path().display().to_string())),
+        )
+    }
+
+    pub fn to_atom(&self, site: &Site) -> String {
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let url = escape_attribute(&absolute_url(site, &entry.route.path()));
+                format!(
+                    "  <entry>
+    <title>{}</title>
+    <link href=\"{url}\"/>
+    <id>{url}</id>
+    <updated>{}</updated>
+    <summary>{}</summary>
+    <content type=\"html\">{}</content>
+  </entry>",
+                    escape_text(&entry.title),
+                    atom_date(entry.date),
+                    escape_text(&entry.summary),
+                    escape_text(&entry.content),
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+        let feed_url =
+            escape_attribute(&absolute_url(site, &self.atom_path().display().to_string()));
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<feed xmlns=\"http://www.w3.org/2005/Atom\">
+  <title>{}</title>
+  <subtitle>{}</subtitle>
+  <link href=\"{}\"/>
+  <link href=\"{feed_url}\" rel=\"self\"/>
+  <id>{feed_url}</id>
+  <updated>{}</updated>
+  <author><name>{}</name></author>
+{entries}
+</feed>
+",
+            escape_text(&self.title),
+            escape_text(&self.description),
+            escape_attribute(&absolute_url(site, "")),
+            atom_date(self.updated()),
+            escape_text(&site.title),
+        )
+    }
+}
+
+// feed readers need absolute links, so feeds can only be published once the site's url is set
+fn absolute_url(site: &Site, path: &str) -> String {
+    let url = site
+        .url
+        .as_ref()
+        .expect("Feeds need the site's url, set it with Site::set_url");
+    format!("{url}{}{path}", site.base_href())
+}
+
+fn atom_date(date: DateTime<Utc>) -> String {
+    date.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{custom::datatypes::PageData, site::Page};
+
+    struct Post(&'static str, i64);
+
+    impl Slug for Post {
+        fn slug(&self) -> &str {
+            self.0
+        }
+    }
+
+    impl FeedItem for Post {
+        fn title(&self) -> &str {
+            self.0
+        }
+
+        fn date(&self) -> DateTime<Utc> {
+            DateTime::from_timestamp(self.1, 0).unwrap()
+        }
+
+        fn summary(&self) -> &str {
+            ""
+        }
+
+        fn content(&self) -> &str {
+            ""
+        }
+    }
+
+    fn site() -> Site {
+        let mut site = Site::new(Page::new("Home", Route::Home, PageData::None), "Test");
+        site.set_url("https://example.com");
+        site
+    }
+
+    #[test]
+    fn empty_feeds_are_the_same_on_every_build() {
+        let feed = || Feed::new::<Post>("Posts", "", "posts", "posts/:slug", []);
+        assert_eq!(feed().to_rss(&site()), feed().to_rss(&site()));
+        assert_eq!(feed().to_atom(&site()), feed().to_atom(&site()));
+        assert!(feed()
+            .to_atom(&site())
+            .contains("<updated>1970-01-01T00:00:00Z</updated>"));
+    }
+
+    #[test]
+    fn feeds_are_dated_by_their_newest_item() {
+        let posts = [Post("old", 0), Post("new", 86400)];
+        let feed = Feed::new("Posts", "", "posts", "posts/:slug", &posts);
+        let atom = feed.to_atom(&site());
+        assert!(atom.contains("  <updated>1970-01-02T00:00:00Z</updated>"));
+        assert!(atom.find("<title>new</title>") < atom.find("<title>old</title>"));
+    }
+}
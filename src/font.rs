@@ -0,0 +1,155 @@
+use crate::{html::escape_attribute, route::under_base_path, ui::FontWeight};
+
+// A font family and what to fall back to while it loads or if it can't, e.g.
+// FontFamily::new("Ubuntu").fallbacks(&["system-ui", "sans-serif"]). It's const so it can be used in global styles, the
+// files it is drawn from are registered on the site with Site::add_font.
+#[derive(Debug, Clone, Copy)]
+pub struct FontFamily {
+    pub name: &'static str,
+    pub fallbacks: &'static [&'static str],
+}
+
+impl FontFamily {
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            fallbacks: &[],
+        }
+    }
+
+    // named families or generic ones like "sans-serif", in order of preference
+    pub const fn fallbacks(self, fallbacks: &'static [&'static str]) -> Self {
+        Self { fallbacks, ..self }
+    }
+}
+
+impl std::fmt::Display for FontFamily {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let stack = std::iter::once(&self.name)
+            .chain(self.fallbacks)
+            .map(|family| family_name(family))
+            .collect::<Vec<String>>()
+            .join(",");
+        write!(f, "{stack}")
+    }
+}
+
+const GENERIC_FAMILIES: &[&str] = &[
+    "serif",
+    "sans-serif",
+    "monospace",
+    "cursive",
+    "fantasy",
+    "system-ui",
+    "ui-serif",
+    "ui-sans-serif",
+    "ui-monospace",
+    "ui-rounded",
+    "emoji",
+    "math",
+    "fangsong",
+];
+
+// generic families are keywords, anything else is quoted so names with spaces or digits are read as one name
+fn family_name(family: &str) -> String {
+    if GENERIC_FAMILIES.contains(&family) {
+        family.to_string()
+    } else {
+        format!("\"{}\"", family.replace('"', "\\\""))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FontStyle {
+    Normal,
+    Italic,
+}
+
+impl std::fmt::Display for FontStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FontStyle::Normal => write!(f, "normal"),
+            FontStyle::Italic => write!(f, "italic"),
+        }
+    }
+}
+
+// One weight and style of a family, e.g. FontFile::new("assets/fonts/Ubuntu-LI.ttf", FontWeight::Light).italic().
+// The path is relative to the site's base path, like routes.
+#[derive(Debug, Clone)]
+pub struct FontFile {
+    pub path: &'static str,
+    pub weight: FontWeight,
+    pub style: FontStyle,
+    // fetched before the stylesheet asks for it, worth it for the faces above the fold and nothing else
+    pub preload: bool,
+}
+
+impl FontFile {
+    pub const fn new(path: &'static str, weight: FontWeight) -> Self {
+        Self {
+            path,
+            weight,
+            style: FontStyle::Normal,
+            preload: false,
+        }
+    }
+
+    pub const fn italic(self) -> Self {
+        Self {
+            style: FontStyle::Italic,
+            ..self
+        }
+    }
+
+    pub const fn preload(self) -> Self {
+        Self {
+            preload: true,
+            ..self
+        }
+    }
+
+    fn format(&self) -> Option<&'static str> {
+        match self.extension() {
+            "woff2" => Some("woff2"),
+            "woff" => Some("woff"),
+            "ttf" => Some("truetype"),
+            "otf" => Some("opentype"),
+            _ => None,
+        }
+    }
+
+    fn extension(&self) -> &str {
+        self.path
+            .rsplit_once('.')
+            .map_or("", |(_, extension)| extension)
+    }
+
+    fn path(&self) -> &str {
+        self.path.trim_start_matches('/')
+    }
+
+    // the path is put under the base path, so the rule works from any stylesheet
+    pub fn to_css(&self, family: &FontFamily, base_href: &str) -> String {
+        let format = self
+            .format()
+            .map(|format| format!(" format(\"{format}\")"))
+            .unwrap_or_default();
+        format!(
+            "@font-face{{font-family:{};src:url(\"{}\"){format};font-weight:{};font-style:{};font-display:swap;}}",
+            family_name(family.name),
+            under_base_path(base_href, self.path()),
+            self.weight,
+            self.style
+        )
+    }
+
+    // preloaded fonts have to be fetched with cors, even from the same origin, or the browser fetches them twice
+    pub fn preload_tag(&self, base_href: &str) -> String {
+        format!(
+            "<link rel=\"preload\" href=\"{}\" as=\"font\" type=\"font/{}\" crossorigin>",
+            escape_attribute(&under_base_path(base_href, self.path())),
+            escape_attribute(self.extension())
+        )
+    }
+}
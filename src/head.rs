@@ -0,0 +1,138 @@
+use crate::{html::escape_attribute, ui::Color};
+
+// Everything in the <head> of a page besides its title and stylesheets. The site holds the defaults and each page can
+// override any of them.
+#[derive(Debug, Clone, Default)]
+pub struct Meta {
+    pub description: Option<String>,
+    // should be an absolute url, since it is read by other sites
+    pub image: Option<String>,
+    // the canonical url, when it isn't set it is worked out from the site's url and the page's route
+    pub url: Option<String>,
+    pub twitter_card: Option<TwitterCard>,
+    pub twitter_site: Option<String>,
+    pub favicon: Option<String>,
+    pub theme_color: Option<Color>,
+}
+
+impl Meta {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn image(mut self, image: &str) -> Self {
+        self.image = Some(image.to_string());
+        self
+    }
+
+    pub fn url(mut self, url: &str) -> Self {
+        self.url = Some(url.to_string());
+        self
+    }
+
+    pub fn twitter_card(mut self, card: TwitterCard) -> Self {
+        self.twitter_card = Some(card);
+        self
+    }
+
+    // the site's twitter handle, e.g. "@sashinexists"
+    pub fn twitter_site(mut self, handle: &str) -> Self {
+        self.twitter_site = Some(handle.to_string());
+        self
+    }
+
+    pub fn favicon(mut self, favicon: &str) -> Self {
+        self.favicon = Some(favicon.to_string());
+        self
+    }
+
+    pub fn theme_color(mut self, color: Color) -> Self {
+        self.theme_color = Some(color);
+        self
+    }
+
+    // anything not set here is taken from the defaults
+    pub fn or(&self, defaults: &Meta) -> Meta {
+        Meta {
+            description: self.description.clone().or(defaults.description.clone()),
+            image: self.image.clone().or(defaults.image.clone()),
+            url: self.url.clone().or(defaults.url.clone()),
+            twitter_card: self.twitter_card.clone().or(defaults.twitter_card.clone()),
+            twitter_site: self.twitter_site.clone().or(defaults.twitter_site.clone()),
+            favicon: self.favicon.clone().or(defaults.favicon.clone()),
+            theme_color: self.theme_color.or(defaults.theme_color),
+        }
+    }
+
+    pub fn to_html(&self, title: &str, site_name: &str) -> String {
+        let tag = |attribute: &str, key: &str, value: &str| {
+            format!(
+                "<meta {attribute}=\"{}\" content=\"{}\">",
+                escape_attribute(key),
+                escape_attribute(value)
+            )
+        };
+        let mut tags = vec![
+            tag("property", "og:type", "website"),
+            tag("property", "og:title", title),
+            tag("property", "og:site_name", site_name),
+        ];
+        if let Some(description) = &self.description {
+            tags.push(tag("name", "description", description));
+            tags.push(tag("property", "og:description", description));
+        }
+        if let Some(image) = &self.image {
+            tags.push(tag("property", "og:image", image));
+        }
+        if let Some(url) = &self.url {
+            tags.push(tag("property", "og:url", url));
+            tags.push(format!(
+                "<link rel=\"canonical\" href=\"{}\">",
+                escape_attribute(url)
+            ));
+        }
+        if let Some(card) = &self.twitter_card {
+            tags.push(tag("name", "twitter:card", &card.to_string()));
+            tags.push(tag("name", "twitter:title", title));
+            if let Some(description) = &self.description {
+                tags.push(tag("name", "twitter:description", description));
+            }
+            if let Some(image) = &self.image {
+                tags.push(tag("name", "twitter:image", image));
+            }
+        }
+        if let Some(handle) = &self.twitter_site {
+            tags.push(tag("name", "twitter:site", handle));
+        }
+        if let Some(favicon) = &self.favicon {
+            tags.push(format!(
+                "<link rel=\"icon\" href=\"{}\">",
+                escape_attribute(favicon)
+            ));
+        }
+        if let Some(color) = &self.theme_color {
+            tags.push(tag("name", "theme-color", &color.rgba()));
+        }
+        tags.join("\n    ")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum TwitterCard {
+    Summary,
+    SummaryLargeImage,
+}
+
+impl std::fmt::Display for TwitterCard {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TwitterCard::Summary => write!(f, "summary"),
+            TwitterCard::SummaryLargeImage => write!(f, "summary_large_image"),
+        }
+    }
+}
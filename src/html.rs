@@ -1,26 +1,47 @@
-use super::Page;
-use crate::ui::{Element, ElementContent, HeadingLevel, Style};
-use std::collections::HashMap;
+use crate::id;
+use crate::route::under_base_path;
+use crate::ui::{Breakpoint, Breakpoints, Element, ElementContent, State, Style};
+use std::collections::BTreeMap;
 #[derive(Debug, Clone)]
 pub struct HtmlElement {
     tag: Tag,
-    attributes: HashMap<String, String>,
+    attributes: BTreeMap<String, String>,
     classes: Vec<String>,
     id: String,
     is_self_closing: bool,
     inner: HtmlInner,
     styles: Vec<Style>,
-    hover_styles: Vec<Style>,
+    state_styles: BTreeMap<State, Vec<Style>>,
+    breakpoint_styles: BTreeMap<Breakpoint, Vec<Style>>,
+    // shared by every element with the same styles, so each rule is only written to the stylesheet once
+    style_class: Option<String>,
 }
 
 impl HtmlElement {
-    pub fn from_element(element: &Element, tag: Tag) -> Self {
+    // position is the element's path from the top of the page, which its id is generated from
+    pub fn from_element(element: &Element, tag: Tag, position: &[usize]) -> Self {
         // Common properties
-        let id = element.id.clone();
-        let classes = element.meta.classes.clone();
-        let styles = element.meta.styles.clone();
+        let id = element
+            .id
+            .clone()
+            .unwrap_or_else(|| id::generate(&element.kind(), position));
+        let styles = unique_styles(&element.meta.styles);
         let attributes = element.meta.attributes.clone();
-        let hover_styles = element.meta.hover_styles.clone();
+        let state_styles = element
+            .meta
+            .state_styles
+            .iter()
+            .map(|(state, styles)| (state.clone(), state_styles(state, styles)))
+            .collect::<BTreeMap<State, Vec<Style>>>();
+        let breakpoint_styles = element
+            .meta
+            .breakpoint_styles
+            .iter()
+            .map(|(breakpoint, styles)| (*breakpoint, unique_styles(styles)))
+            .collect::<BTreeMap<Breakpoint, Vec<Style>>>();
+        let style_class = style_class(&styles, &state_styles, &breakpoint_styles);
+        let mut classes = element.meta.classes.clone();
+        classes.extend(style_class.clone());
         let is_self_closing = match tag {
             Tag::IMG => true,
             _ => false,
@@ -28,25 +49,50 @@ impl HtmlElement {
 
         // Specific HtmlInner based on ElementContent
         let inner = match &element.content {
-            ElementContent::Column(column) => HtmlInner::Children(
-                column
-                    .elements
-                    .iter()
-                    .map(|el| Self::from_element(el, el.get_tag()))
-                    .collect(),
-            ),
-            ElementContent::Row(row) => HtmlInner::Children(
-                row.elements
-                    .iter()
-                    .map(|el| Self::from_element(el, el.get_tag()))
-                    .collect(),
-            ),
-            ElementContent::Text(text) => HtmlInner::Content(text.content.clone()),
-            ElementContent::Link(link) => {
-                HtmlInner::Children(vec![Self::from_element(&link.label, link.label.get_tag())])
+            ElementContent::Column(column) => {
+                HtmlInner::Children(Self::from_children(&column.elements, position))
+            }
+            ElementContent::Row(row) => {
+                HtmlInner::Children(Self::from_children(&row.elements, position))
+            }
+            ElementContent::Grid(grid) => {
+                HtmlInner::Children(Self::from_children(&grid.elements, position))
             }
-            ElementContent::Heading(heading) => HtmlInner::Content(vec![heading.content.clone()]),
+            ElementContent::List(list) => {
+                HtmlInner::Children(Self::from_children(&list.elements, position))
+            }
+            ElementContent::ListItem(item) => {
+                HtmlInner::Children(Self::from_children(&item.elements, position))
+            }
+            ElementContent::Table(table) => {
+                HtmlInner::Children(Self::from_children(&table.parts(), position))
+            }
+            ElementContent::TablePart(part) => {
+                HtmlInner::Children(Self::from_children(&part.elements, position))
+            }
+            ElementContent::TableCell(cell) => {
+                HtmlInner::Children(Self::from_children(&cell.elements, position))
+            }
+            ElementContent::Text(text) if text.is_inline() => {
+                HtmlInner::Content(text.paragraphs.iter().map(paragraph_content).collect())
+            }
+            ElementContent::Text(text) => HtmlInner::Children(Self::from_children(
+                &text
+                    .paragraphs
+                    .iter()
+                    .map(|paragraph| paragraph.clone().add_styles(&text.paragraph_styles))
+                    .collect::<Vec<Element>>(),
+                position,
+            )),
+            ElementContent::Paragraph(paragraph) => HtmlInner::Content(paragraph.content.clone()),
+            ElementContent::Link(link) => HtmlInner::Children(vec![Self::from_element(
+                &link.label,
+                link.label.get_tag(),
+                &[position, &[0]].concat(),
+            )]),
+            ElementContent::Heading(heading) => HtmlInner::Content(heading.content.clone()),
             ElementContent::Image(_) => HtmlInner::None,
+            ElementContent::RawHtml(raw) => HtmlInner::Raw(raw.html.clone()),
         };
 
         Self {
@@ -57,24 +103,34 @@ impl HtmlElement {
             classes,
             styles,
             inner,
-            hover_styles,
+            state_styles,
+            breakpoint_styles,
+            style_class,
         }
     }
 
+    fn from_children(elements: &[Element], position: &[usize]) -> Vec<Self> {
+        elements
+            .iter()
+            .enumerate()
+            .map(|(index, el)| Self::from_element(el, el.get_tag(), &[position, &[index]].concat()))
+            .collect()
+    }
+
     pub fn write_html(&self) -> String {
         if self.is_self_closing {
             format!(
-                "<{} id='{}' {} {}/>",
+                "<{} id=\"{}\" {} {}/>",
                 self.tag,
-                self.id,
+                escape_attribute(&self.id),
                 self.get_attribute_string(),
                 self.get_class_string()
             )
         } else {
             format!(
-                "<{} id='{}' {} {}>{}</{}>",
+                "<{} id=\"{}\" {} {}>{}</{}>",
                 self.tag,
-                self.id,
+                escape_attribute(&self.id),
                 self.get_attribute_string(),
                 self.get_class_string(),
                 self.inner.write_html(),
@@ -83,35 +139,99 @@ impl HtmlElement {
         }
     }
 
-    pub fn write_css(&self) -> String {
-        self.build_stylesheet().to_css()
+    pub fn stylesheet(&self) -> Stylesheet {
+        Stylesheet::from_html_element(self)
     }
 
-    fn build_stylesheet(&self) -> Stylesheet {
-        Stylesheet::from_html_element(self)
+    // pages don't have a <base>, so "#id" stays on the page and links into the site are written under its base path
+    pub fn under_base_path(mut self, base_href: &str) -> Self {
+        ["href", "src"].iter().for_each(|attribute| {
+            if let Some(value) = self.attributes.get_mut(*attribute) {
+                *value = under_base_path(base_href, value);
+            }
+        });
+        if let HtmlInner::Children(children) = self.inner {
+            self.inner = HtmlInner::Children(
+                children
+                    .into_iter()
+                    .map(|child| child.under_base_path(base_href))
+                    .collect(),
+            );
+        }
+        self
     }
 
     fn get_attribute_string(&self) -> String {
         self.attributes
             .iter()
-            .map(|(k, v)| format!("{k}='{v}'"))
+            .map(|(k, v)| format!("{}=\"{}\"", escape_attribute(k), escape_attribute(v)))
             .collect::<Vec<String>>()
             .join(" ")
     }
 
     fn get_class_string(&self) -> String {
-        if &self.classes.len() > &0 {
-            "class='".to_string() + &self.classes.join(" ") + "'"
+        if !self.classes.is_empty() {
+            format!("class=\"{}\"", escape_attribute(&self.classes.join(" ")))
         } else {
             String::new()
         }
     }
 }
 
+// if a style is given twice the first one is kept
+fn unique_styles(styles: &[Style]) -> Vec<Style> {
+    styles.iter().fold(Vec::new(), |mut unique, style| {
+        if !unique.iter().any(|existing| existing.variant_eq(style)) {
+            unique.push(style.clone());
+        }
+        unique
+    })
+}
+
+fn state_styles(state: &State, styles: &[Style]) -> Vec<Style> {
+    let content = state
+        .content()
+        .map(|content| Style::Content(content.to_string()));
+    unique_styles(
+        &content
+            .into_iter()
+            .chain(styles.iter().cloned())
+            .collect::<Vec<Style>>(),
+    )
+}
+
+fn declarations(styles: &[Style]) -> String {
+    styles.iter().map(|style| style.to_string()).collect()
+}
+
+fn style_class(
+    styles: &[Style],
+    state_styles: &BTreeMap<State, Vec<Style>>,
+    breakpoint_styles: &BTreeMap<Breakpoint, Vec<Style>>,
+) -> Option<String> {
+    if styles.is_empty() && state_styles.is_empty() && breakpoint_styles.is_empty() {
+        return None;
+    }
+    let states = state_styles
+        .iter()
+        .map(|(state, styles)| format!("|{}|{}", state.selector(), declarations(styles)))
+        .collect::<String>();
+    let breakpoints = breakpoint_styles
+        .iter()
+        .map(|(breakpoint, styles)| format!("|{breakpoint:?}|{}", declarations(styles)))
+        .collect::<String>();
+    Some(id::class(&format!(
+        "{}{states}{breakpoints}",
+        declarations(styles)
+    )))
+}
+
 #[derive(Debug, Clone)]
 enum HtmlInner {
     Children(Vec<HtmlElement>),
-    Content(Vec<String>),
+    Content(String),
+    // trusted markup from raw_html, written as it is
+    Raw(String),
     None,
 }
 
@@ -123,31 +243,74 @@ impl HtmlInner {
                 .map(|child| child.write_html())
                 .collect::<Vec<String>>()
                 .join("\n"),
-            Self::Content(content) => paragraphs_to_html(content.clone()),
+            Self::Content(content) => escape_text(content),
+            Self::Raw(html) => html.clone(),
             Self::None => "".to_string(),
         }
     }
 }
 
-fn paragraphs_to_html(paragraphs: Vec<String>) -> String {
-    // inserts the text content into a span if there is only one item, or into paragraphs if there are multiple
-    if paragraphs.len() == 1 {
-        paragraphs[0].to_string()
-    } else {
-        paragraphs
-            .iter()
-            .map(|paragraph| {
-                format!("<p {{attributes}} class=~~classes~~ style=~~styles~~>{paragraph}</p>")
-            })
-            .collect::<Vec<String>>()
-            .join("\n")
+fn paragraph_content(paragraph: &Element) -> String {
+    match &paragraph.content {
+        ElementContent::Paragraph(paragraph) => paragraph.content.clone(),
+        _ => panic!("Expected a paragraph in a text element. {:?}", paragraph),
     }
 }
 
-#[derive(Debug, Clone)]
+// for text between tags
+pub fn escape_text(text: &str) -> String {
+    text.chars().fold(String::new(), |mut escaped, character| {
+        match character {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            _ => escaped.push(character),
+        }
+        escaped
+    })
+}
+
+// for attribute values, which are always written in double quotes
+pub fn escape_attribute(value: &str) -> String {
+    escape_text(value)
+        .chars()
+        .fold(String::new(), |mut escaped, character| {
+            match character {
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&#39;"),
+                _ => escaped.push(character),
+            }
+            escaped
+        })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Tag {
     Div,
+    // the sectioning elements a column, row or grid can be written as with Element::with_tag
+    Header,
+    Nav,
+    Main,
+    Section,
+    Article,
+    Footer,
+    Aside,
+    Ul,
+    Ol,
+    Li,
+    Dl,
+    Dt,
+    Dd,
+    Table,
+    Caption,
+    Thead,
+    Tbody,
+    Tfoot,
+    Tr,
+    Th,
+    Td,
     Span,
+    P,
     A,
     H1,
     H2,
@@ -162,7 +325,29 @@ impl std::fmt::Display for Tag {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Tag::Div => write!(f, "div"),
+            Tag::Header => write!(f, "header"),
+            Tag::Nav => write!(f, "nav"),
+            Tag::Main => write!(f, "main"),
+            Tag::Section => write!(f, "section"),
+            Tag::Article => write!(f, "article"),
+            Tag::Footer => write!(f, "footer"),
+            Tag::Aside => write!(f, "aside"),
+            Tag::Ul => write!(f, "ul"),
+            Tag::Ol => write!(f, "ol"),
+            Tag::Li => write!(f, "li"),
+            Tag::Dl => write!(f, "dl"),
+            Tag::Dt => write!(f, "dt"),
+            Tag::Dd => write!(f, "dd"),
+            Tag::Table => write!(f, "table"),
+            Tag::Caption => write!(f, "caption"),
+            Tag::Thead => write!(f, "thead"),
+            Tag::Tbody => write!(f, "tbody"),
+            Tag::Tfoot => write!(f, "tfoot"),
+            Tag::Tr => write!(f, "tr"),
+            Tag::Th => write!(f, "th"),
+            Tag::Td => write!(f, "td"),
             Tag::Span => write!(f, "span"),
+            Tag::P => write!(f, "p"),
             Tag::A => write!(f, "a"),
             Tag::H1 => write!(f, "h1"),
             Tag::H2 => write!(f, "h2"),
@@ -193,69 +378,102 @@ impl CSSRuleSet {
     }
 }
 
+// ordered by class and then by state, so an element's rules are written together and in the order State declares
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct Selector {
+    class: String,
+    state: Option<State>,
+}
+
+impl std::fmt::Display for Selector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.state {
+            Some(state) => write!(f, ".{}{}", self.class, state.selector()),
+            None => write!(f, ".{}", self.class),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
-struct Stylesheet(HashMap<String, Vec<Style>>);
+pub struct Stylesheet {
+    rules: BTreeMap<Selector, Vec<Style>>,
+    // written in @media blocks after the other rules, so they override them
+    media_rules: BTreeMap<Breakpoint, BTreeMap<Selector, Vec<Style>>>,
+}
 
 impl Stylesheet {
     pub fn new() -> Self {
-        Self(HashMap::<String, Vec<Style>>::new())
+        Self {
+            rules: BTreeMap::new(),
+            media_rules: BTreeMap::new(),
+        }
     }
-    //this is what you are up to, you just changed stylesheet to a hashmap and you're dealing with the fallout
-    pub fn to_css(&self) -> String {
-        self.0
-            .iter()
-            .map(|(selector, styles)| CSSRuleSet(selector.clone(), styles.clone()).to_css())
+
+    // every style of every rule, including the ones in @media blocks
+    pub fn styles(&self) -> impl Iterator<Item = &Style> {
+        self.rules
+            .values()
+            .chain(self.media_rules.values().flat_map(|rules| rules.values()))
+            .flatten()
+    }
+
+    pub fn to_css(&self, breakpoints: &Breakpoints) -> String {
+        let media_blocks = self.media_rules.iter().map(|(breakpoint, rules)| {
+            format!(
+                "@media {}{{\n{}\n}}",
+                breakpoints.media_query(*breakpoint),
+                rules_to_css(rules)
+            )
+        });
+        std::iter::once(rules_to_css(&self.rules))
+            .chain(media_blocks)
+            .filter(|css| !css.is_empty())
             .collect::<Vec<String>>()
             .join("\n")
     }
 
-    pub fn from_page(page: &Page) -> Self {
-        page.content
-            .iter()
-            .fold(Self::new(), |mut stylesheet, element| {
-                stylesheet
-                    .0
-                    .extend(Stylesheet::from_element(element, Stylesheet::new()).0);
-                stylesheet
-            })
-    }
-    //This is what you are up to
-    fn from_element(element: &Element, mut reducer: Self) -> Self {
-        reducer
-            .0
-            .insert(
-                "#".to_string() + &element.id.to_string(),
-                element.meta.styles.clone(),
+    // rules are keyed by selector, and the selectors are hashes of their styles, so merging never duplicates a rule
+    pub fn merge(mut self, other: Self) -> Self {
+        other
+            .rules
+            .into_iter()
+            .for_each(|(selector, styles)| insert_rule(&mut self.rules, selector, styles));
+        other
+            .media_rules
+            .into_iter()
+            .for_each(|(breakpoint, rules)| {
+                let merged = self.media_rules.entry(breakpoint).or_default();
+                rules
+                    .into_iter()
+                    .for_each(|(selector, styles)| insert_rule(merged, selector, styles));
+            });
+        self
+    }
+
+    // urls in the styles point into the site from wherever the stylesheet is written, see Style::under_base_path
+    pub fn under_base_path(mut self, base_href: &str) -> Self {
+        self.rules
+            .values_mut()
+            .chain(
+                self.media_rules
+                    .values_mut()
+                    .flat_map(|rules| rules.values_mut()),
             )
-            .expect("Failed to insert ({}, {}) into the stylesheet");
-        match element.content.clone() {
-            ElementContent::Column(column) => column
-                .elements
-                .iter()
-                .map(|el| Self::from_element(el, reducer.clone()))
-                .collect::<Vec<Stylesheet>>()
-                .iter()
-                .fold(reducer.clone(), |mut output, stylesheet| {
-                    stylesheet.0.iter().for_each(|(separator, styles)| {
-                        reducer.0.insert(separator.clone(), styles.clone());
-                    });
-                    reducer.clone()
-                }),
-
-            ElementContent::Row(row) => row
-                .elements
-                .iter()
-                .map(|el| Self::from_element(el, reducer.clone()))
-                .collect::<Vec<Stylesheet>>()
-                .iter()
-                .fold(reducer.clone(), |mut output, stylesheet| {
-                    stylesheet.0.iter().for_each(|(separator, styles)| {
-                        reducer.0.insert(separator.clone(), styles.clone());
-                    });
-                    reducer.clone()
-                }),
-            _ => reducer,
-        }
+            .flatten()
+            .for_each(|style| *style = style.clone().under_base_path(base_href));
+        self
+    }
+
+    pub fn without(mut self, other: &Self) -> Self {
+        self.rules
+            .retain(|selector, _| !other.rules.contains_key(selector));
+        self.media_rules.iter_mut().for_each(|(breakpoint, rules)| {
+            if let Some(other_rules) = other.media_rules.get(breakpoint) {
+                rules.retain(|selector, _| !other_rules.contains_key(selector));
+            }
+        });
+        self.media_rules.retain(|_, rules| !rules.is_empty());
+        self
     }
 
     pub fn from_html_element(element: &HtmlElement) -> Self {
@@ -265,19 +483,22 @@ impl Stylesheet {
     }
 
     fn populate_from_element(sheet: &mut Stylesheet, element: &HtmlElement) {
-        // Generate selector based on id
-        let selector = format!("#{}", element.id);
-
-        // Add or merge styles
-        let entry = sheet.0.entry(selector).or_insert_with(Vec::new);
-
-        for new_style in &element.styles {
-            let is_present = entry
+        if let Some(class) = &element.style_class {
+            let selector = |state: Option<&State>| Selector {
+                class: class.clone(),
+                state: state.cloned(),
+            };
+            sheet.add_rule(selector(None), &element.styles);
+            element
+                .state_styles
                 .iter()
-                .any(|existing_style| existing_style.variant_eq(new_style));
-            if !is_present {
-                entry.push(new_style.clone());
-            }
+                .for_each(|(state, styles)| sheet.add_rule(selector(Some(state)), styles));
+            element
+                .breakpoint_styles
+                .iter()
+                .for_each(|(breakpoint, styles)| {
+                    sheet.add_media_rule(*breakpoint, selector(None), styles)
+                });
         }
 
         // Recurse into children if any
@@ -286,25 +507,208 @@ impl Stylesheet {
                 Stylesheet::populate_from_element(sheet, child);
             }
         }
-        let selector = format!("#{}:hover", element.id);
-
-        // Add or merge styles
-        let entry = sheet.0.entry(selector).or_insert_with(Vec::new);
+    }
 
-        for new_style in &element.hover_styles {
-            let is_present = entry
-                .iter()
-                .any(|existing_style| existing_style.variant_eq(new_style));
-            if !is_present {
-                entry.push(new_style.clone());
-            }
+    fn add_rule(&mut self, selector: Selector, styles: &[Style]) {
+        if !styles.is_empty() {
+            insert_rule(&mut self.rules, selector, styles.to_vec());
         }
+    }
 
-        // Recurse into children if any
-        if let HtmlInner::Children(children) = &element.inner {
-            for child in children {
-                Stylesheet::populate_from_element(sheet, child);
-            }
+    fn add_media_rule(&mut self, breakpoint: Breakpoint, selector: Selector, styles: &[Style]) {
+        if !styles.is_empty() {
+            insert_rule(
+                self.media_rules.entry(breakpoint).or_default(),
+                selector,
+                styles.to_vec(),
+            );
         }
     }
 }
+
+// classes are 32 bit hashes, so two different style sets can get the same one, which would silently restyle one of the
+// elements with the other's rule
+fn insert_rule(rules: &mut BTreeMap<Selector, Vec<Style>>, selector: Selector, styles: Vec<Style>) {
+    let declarations = |styles: &[Style]| {
+        styles
+            .iter()
+            .map(|style| style.to_string())
+            .collect::<String>()
+    };
+    if let Some(existing) = rules.get(&selector) {
+        assert!(
+            declarations(existing) == declarations(&styles),
+            "Two different style sets have the class {selector}: {{{}}} and {{{}}}",
+            declarations(existing),
+            declarations(&styles)
+        );
+    }
+    rules.insert(selector, styles);
+}
+
+fn rules_to_css(rules: &BTreeMap<Selector, Vec<Style>>) -> String {
+    rules
+        .iter()
+        .map(|(selector, styles)| CSSRuleSet(selector.to_string(), styles.clone()).to_css())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{
+        column, header_cell, heading, image, link, paragraph, paragraphs, raw_html, row, table_of,
+        text, Breakpoints, Color, HeadingLevel, Landmark, Scope, State, TableColumn, Unit,
+    };
+
+    fn render(element: &Element) -> String {
+        HtmlElement::from_element(element, element.get_tag(), &[0]).write_html()
+    }
+
+    #[test]
+    fn paragraphs_of_one_text_is_written_inline() {
+        assert_eq!(
+            render(&paragraphs(vec![text("x")])),
+            render(&paragraphs(vec![paragraph("x")]))
+        );
+        assert_eq!(
+            render(&paragraphs(vec![text("x")])),
+            "<span id=\"text-0\"  >x</span>"
+        );
+    }
+
+    #[test]
+    fn paragraphs_of_one_heading_is_written_as_a_child() {
+        assert_eq!(
+            render(&paragraphs(vec![heading(HeadingLevel::H2, "x")])),
+            "<div id=\"text-0\"  ><h2 id=\"h2-0-0\"  >x</h2></div>"
+        );
+    }
+
+    #[test]
+    fn escapes_text() {
+        assert_eq!(
+            escape_text("<b>Tom & Jerry's \"show\"</b>"),
+            "&lt;b&gt;Tom &amp; Jerry's \"show\"&lt;/b&gt;"
+        );
+        assert_eq!(escape_text("&amp;"), "&amp;amp;");
+        assert_eq!(escape_text("plain"), "plain");
+    }
+
+    #[test]
+    fn escapes_attributes() {
+        assert_eq!(
+            escape_attribute("a\" onclick='x' <&>"),
+            "a&quot; onclick=&#39;x&#39; &lt;&amp;&gt;"
+        );
+    }
+
+    #[test]
+    fn escapes_text_and_attributes_of_elements_but_not_raw_html() {
+        assert_eq!(
+            render(&text("<script>")),
+            "<span id=\"text-0\"  >&lt;script&gt;</span>"
+        );
+        assert_eq!(
+            render(&image("a.png\" onerror=\"x", "<alt>")),
+            "<img id=\"image-0\" alt=\"&lt;alt&gt;\" src=\"a.png&quot; onerror=&quot;x\" />"
+        );
+        assert!(render(&link(text("a"), "?q=\"1\"")).contains("href=\"?q=&quot;1&quot;\""));
+        assert!(render(&raw_html("<b>bold</b>")).contains("<b>bold</b>"));
+    }
+
+    fn page() -> Element {
+        column()
+            .add_styles(&[
+                Style::Padding(Unit::Px(10)),
+                Style::TextColor(Color::new(1, 2, 3, 1.0)),
+            ])
+            .push(
+                row()
+                    .push(text("a"))
+                    .add_state_style(State::Hover, Style::Opacity(0.5)),
+            )
+            .push(
+                row()
+                    .push(text("b"))
+                    .add_styles(&[Style::Padding(Unit::Px(10))]),
+            )
+    }
+
+    #[test]
+    fn landmarks_change_the_tag_but_not_the_children() {
+        let html = render(&column().with_tag(Landmark::Nav).push(text("x")));
+        assert!(html.starts_with("<nav "), "{html}");
+        assert!(html.ends_with("</nav>"), "{html}");
+        assert!(html.contains(">x</span>"), "{html}");
+    }
+
+    #[test]
+    fn tables_of_items_have_a_header_row_and_a_row_per_item() {
+        let html = render(
+            &table_of(
+                &["a", "b"],
+                &[TableColumn::new("Letter", |letter: &&str| {
+                    header_cell(Scope::Row).push(text(letter))
+                })],
+            )
+            .with_caption(text("Letters")),
+        );
+        assert!(html.contains(
+            "<caption id=\"caption-0-0\"  ><span id=\"text-0-0-0\"  >Letters</span></caption>"
+        ));
+        assert!(html.contains(
+            "<thead id=\"thead-0-1\"  ><tr id=\"tr-0-1-0\"  ><th id=\"th-0-1-0-0\" scope=\"col\" >"
+        ));
+        assert_eq!(html.matches("<tr ").count(), 3);
+        assert_eq!(html.matches("scope=\"row\"").count(), 2);
+        assert!(!html.contains("<tfoot"));
+    }
+
+    #[test]
+    fn the_same_class_twice_is_written_once() {
+        let selector = || Selector {
+            class: "s-1".to_string(),
+            state: None,
+        };
+        let mut stylesheet = Stylesheet::new();
+        stylesheet.add_rule(selector(), &[Style::Padding(Unit::Px(1))]);
+        let mut other = Stylesheet::new();
+        other.add_rule(selector(), &[Style::Padding(Unit::Px(1))]);
+        assert_eq!(
+            stylesheet.merge(other).to_css(&Breakpoints::default()),
+            ".s-1{padding:1px;}"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Two different style sets have the class .s-1")]
+    fn classes_shared_by_different_styles_fail_the_build() {
+        let selector = || Selector {
+            class: "s-1".to_string(),
+            state: None,
+        };
+        let mut stylesheet = Stylesheet::new();
+        stylesheet.add_rule(selector(), &[Style::Padding(Unit::Px(1))]);
+        let mut other = Stylesheet::new();
+        other.add_rule(selector(), &[Style::Padding(Unit::Px(2))]);
+        stylesheet.merge(other);
+    }
+
+    // each build starts from scratch, so the same elements have to give the same bytes every time
+    #[test]
+    fn building_twice_gives_identical_output() {
+        let build = || {
+            let html = HtmlElement::from_element(&page(), page().get_tag(), &[0]);
+            (
+                html.write_html(),
+                html.stylesheet().to_css(&Breakpoints::default()),
+            )
+        };
+        let (html, css) = build();
+        assert_eq!(build(), (html.clone(), css.clone()));
+        assert!(html.starts_with("<div id=\"column-0\""));
+        assert!(html.contains("id=\"row-0-0\"") && html.contains("id=\"text-0-1-0\""));
+    }
+}
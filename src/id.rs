@@ -1,8 +1,34 @@
-use std::sync::atomic::{AtomicUsize, Ordering};
+// ids come from where an element sits in its page, e.g. the second child of the first element is "row-0-1",
+// so building the same site twice produces the same ids
+pub fn generate(kind: &str, position: &[usize]) -> String {
+    position
+        .iter()
+        .fold(kind.to_string(), |id, index| format!("{id}-{index}"))
+}
+
+// a class name made from a hash of the declarations it stands for, FNV-1a so it is the same on every build
+pub fn class(declarations: &str) -> String {
+    let hash = declarations.bytes().fold(0x811c9dc5_u32, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x01000193)
+    });
+    format!("s-{hash:08x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-static ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
+    #[test]
+    fn ids_follow_the_position() {
+        assert_eq!(generate("row", &[0, 1]), "row-0-1");
+        assert_eq!(generate("text", &[]), "text");
+    }
 
-pub fn generate() -> String {
-    let id = ID_COUNTER.fetch_add(1, Ordering::SeqCst);
-    format!("{}", id)
+    // the FNV-1a test vectors, so a class never changes between builds or versions of rust
+    #[test]
+    fn classes_are_fnv1a_hashes() {
+        assert_eq!(class(""), "s-811c9dc5");
+        assert_eq!(class("a"), "s-e40c292c");
+        assert_eq!(class("foobar"), "s-bf9cf968");
+    }
 }
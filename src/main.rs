@@ -8,24 +8,42 @@ use custom::theme::*;
 #[macro_use]
 mod ui;
 use ui::*;
+mod cli;
+mod feed;
+use feed::Feed;
+mod font;
+mod head;
+use head::{Meta, TwitterCard};
 mod id;
+pub mod route;
+mod scaffold;
+mod serve;
 pub mod site;
+mod sitemap;
+mod theme;
 use site::*;
+use sitemap::Robots;
 
 use crate::custom::api::{get_past_projects_data, get_skills_data, get_testimonials_data};
 use crate::custom::datatypes::Home;
-use crate::custom::pages;
+use crate::custom::{pages, routes};
 fn main() {
+    cli::run(build);
+}
+
+fn build() -> Site {
     dotenv().expect("Failed to read .env file"); // This line loads the .env file into environment variables
     let access_token = env::var("CONTENTFUL_CONTENT_DELIVERY_API_ACCESS_TOKEN")
         .expect("CONTENTFUL_ACCESS_TOKEN not found");
     let space_id = env::var("CONTENTFUL_SPACE_ID").expect("CONTENTFUL_SPACE_ID not found");
 
+    check_contrast();
+
     const GLOBAL_STYLES: &[Style] = &[
         Style::BackgroundColor(colors::RICH_BLACK),
         Style::Margin(Unit::Px(0)),
         Style::Width(Unit::Percent(100.0)),
-        Style::Font("Ubuntu"),
+        Style::Font(UBUNTU),
         Style::TextColor(colors::DARK_MEDIUM_GRAY),
     ];
 
@@ -34,12 +52,35 @@ fn main() {
 
     let testimonials_page = pages::testimonials(&model.testimonials);
     let projects_page = pages::projects(&model.past_projects);
-    let home = Page::new("Sashin Dev", ".public/index.html", PageData::Home(model));
+    let home = Page::new("Sashin Dev", routes::HOME, PageData::Home(model.clone()));
 
     let mut site = Site::new(home, "Sashin Dev");
     site.add_page(testimonials_page);
     site.add_page(projects_page);
+    site.add_dynamic_pages(routes::PROJECT, model.past_projects.iter(), pages::project);
+    site.add_dynamic_pages(routes::TESTIMONIAL, model.testimonials.iter(), pages::testimonial);
     site.home.push(main);
     site.add_global_styles(GLOBAL_STYLES);
-    site.publish();
+    site.set_theme(site_theme());
+    site.add_font(UBUNTU, UBUNTU_FILES);
+    site.add_keyframes(fade_in());
+    site.respect_reduced_motion();
+    site.set_assets_dir(".public/assets");
+    site.set_url("https://sashin.dev");
+    site.enable_sitemap();
+    site.set_robots(Robots::allow_all());
+    site.add_feed(Feed::new(
+        "Sashin Dev - Past Projects",
+        "Websites and software Sashin has built",
+        "past-projects",
+        routes::PROJECT,
+        model.past_projects.iter(),
+    ));
+    site.set_meta(
+        Meta::new()
+            .description("Sashin Dev, websites and software built by Sashin")
+            .twitter_card(TwitterCard::SummaryLargeImage)
+            .theme_color(colors::RICH_BLACK),
+    );
+    site
 }
@@ -0,0 +1,137 @@
+use std::path::PathBuf;
+
+// A route is declared once (see custom/routes.rs) and used both to decide where a page is
+// written and to build the hrefs that point at it, so the two can't drift apart.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Route {
+    Home,
+    Static(&'static str),
+    // a pattern such as "projects/:slug" together with the slug it was filled in with
+    Dynamic(&'static str, String),
+}
+
+impl Route {
+    pub fn dynamic(pattern: &'static str, slug: &str) -> Self {
+        Self::Dynamic(pattern, slug.to_string())
+    }
+
+    // the path of the route without any leading or trailing slashes, e.g. "projects/pages"
+    pub fn path(&self) -> String {
+        match self {
+            Self::Home => String::new(),
+            Self::Static(path) => path.trim_matches('/').to_string(),
+            Self::Dynamic(pattern, slug) => pattern
+                .trim_matches('/')
+                .split('/')
+                .map(|segment| {
+                    if segment.starts_with(':') {
+                        slug.as_str()
+                    } else {
+                        segment
+                    }
+                })
+                .collect::<Vec<&str>>()
+                .join("/"),
+        }
+    }
+
+    // root-relative, pages are written with it under the site's base path, see under_base_path
+    pub fn href(&self) -> String {
+        format!("/{}", self.path())
+    }
+
+    pub fn file_path(&self) -> PathBuf {
+        match self {
+            Self::Home => PathBuf::from("index.html"),
+            _ => PathBuf::from(format!("{}.html", self.path())),
+        }
+    }
+}
+
+impl std::fmt::Display for Route {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.href())
+    }
+}
+
+// Puts an href or src that points into the site under its base path, e.g. "/projects/pages" or "assets/logo.png"
+// becomes "/blog/projects/pages" or "/blog/assets/logo.png" when the site is served from example.com/blog. Anchors like
+// "#top", queries and urls with a scheme or host are left as they are. The base can be given with or without slashes.
+pub fn under_base_path(base_href: &str, href: &str) -> String {
+    let has_scheme = href.split_once(':').is_some_and(|(scheme, _)| {
+        !scheme.is_empty()
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    });
+    if has_scheme || href.starts_with("//") || href.starts_with('#') || href.starts_with('?') {
+        href.to_string()
+    } else {
+        let base = base_href.trim_matches('/');
+        let href = href.trim_start_matches('/');
+        if base.is_empty() {
+            format!("/{href}")
+        } else {
+            format!("/{base}/{href}")
+        }
+    }
+}
+
+// implemented by items in a collection that get a page of their own on a dynamic route
+pub trait Slug {
+    fn slug(&self) -> &str;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths_stay_at_the_root_without_a_base() {
+        for base in ["", "/"] {
+            assert_eq!(under_base_path(base, "/"), "/");
+            assert_eq!(under_base_path(base, "/projects/pages"), "/projects/pages");
+            assert_eq!(under_base_path(base, "assets/logo.png"), "/assets/logo.png");
+        }
+    }
+
+    #[test]
+    fn paths_go_under_the_base_however_it_is_slashed() {
+        for base in ["blog", "/blog", "blog/", "/blog/"] {
+            assert_eq!(under_base_path(base, "/"), "/blog/");
+            assert_eq!(
+                under_base_path(base, "/projects/pages"),
+                "/blog/projects/pages"
+            );
+            assert_eq!(
+                under_base_path(base, "assets/logo.png"),
+                "/blog/assets/logo.png"
+            );
+        }
+        assert_eq!(under_base_path("/a/b/", "/c"), "/a/b/c");
+    }
+
+    #[test]
+    fn urls_anchors_and_queries_are_left_alone() {
+        for href in [
+            "https://sashin.dev/projects",
+            "http://example.com",
+            "//cdn.example.com/font.woff2",
+            "mailto:hi@sashin.dev",
+            "tel:+61400000000",
+            "#top",
+            "?page=2",
+        ] {
+            assert_eq!(under_base_path("/blog/", href), href);
+        }
+    }
+
+    #[test]
+    fn routes_are_written_under_the_base() {
+        assert_eq!(under_base_path("/blog/", &Route::Home.href()), "/blog/");
+        assert_eq!(
+            under_base_path("/blog/", &Route::dynamic("projects/:slug", "pages").href()),
+            "/blog/projects/pages"
+        );
+    }
+}
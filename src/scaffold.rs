@@ -0,0 +1,244 @@
+use std::{fs, path::Path};
+
+// The framework modules are copied into every new project as they are, the custom/ modules are where the new site lives
+const FRAMEWORK_FILES: &[(&str, &str)] = &[
+    ("src/cli.rs", include_str!("cli.rs")),
+    ("src/feed.rs", include_str!("feed.rs")),
+    ("src/font.rs", include_str!("font.rs")),
+    ("src/head.rs", include_str!("head.rs")),
+    ("src/html.rs", include_str!("html.rs")),
+    ("src/id.rs", include_str!("id.rs")),
+    ("src/route.rs", include_str!("route.rs")),
+    ("src/scaffold.rs", include_str!("scaffold.rs")),
+    ("src/serve.rs", include_str!("serve.rs")),
+    ("src/site.rs", include_str!("site.rs")),
+    ("src/sitemap.rs", include_str!("sitemap.rs")),
+    ("src/theme.rs", include_str!("theme.rs")),
+    ("src/ui.rs", include_str!("ui.rs")),
+];
+
+const CARGO_TOML: &str = r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+chrono = "0.4.31"
+clap = { version = "4.4.6", features = ["derive"] }
+"#;
+
+const GITIGNORE: &str = "/target\nCargo.lock\n";
+
+const MAIN: &str = r#"mod custom;
+mod html;
+#[macro_use]
+mod ui;
+use ui::*;
+mod cli;
+mod feed;
+mod font;
+mod head;
+mod id;
+pub mod route;
+mod scaffold;
+mod serve;
+pub mod site;
+mod sitemap;
+mod theme;
+use site::*;
+
+use crate::custom::pages;
+
+fn main() {
+    cli::run(build);
+}
+
+fn build() -> Site {
+    Site::new(pages::home(), "{title}")
+}
+"#;
+
+const CUSTOM_MOD: &str = "pub mod api;
+pub mod components;
+pub mod datatypes;
+pub mod pages;
+pub mod routes;
+pub mod theme;
+";
+
+const CUSTOM_API: &str = "// fetch the data for your site here and parse it into the types in datatypes.rs
+";
+
+const CUSTOM_DATATYPES: &str = "use crate::ui::Element;
+
+#[derive(Debug, Clone)]
+pub enum PageData {
+    None,
+}
+
+pub trait View {
+    fn view(&self) -> Element;
+}
+";
+
+const CUSTOM_COMPONENTS: &str = "use crate::ui::*;
+
+pub fn page_template(content: Element) -> Element {
+    column().add_style(Style::Center).push(content)
+}
+";
+
+const CUSTOM_PAGES: &str = r#"use crate::{site::Page, ui::*};
+
+use super::{components::page_template, datatypes::PageData, routes};
+
+pub fn home() -> Page {
+    let mut home = Page::new("{title}", routes::HOME, PageData::None);
+    home.push(page_template(heading(HeadingLevel::H1, "{title}")));
+    home
+}
+"#;
+
+const CUSTOM_ROUTES: &str = "use crate::route::Route;
+
+pub const HOME: Route = Route::Home;
+";
+
+const CUSTOM_THEME: &str = "pub mod colors {
+    use crate::Color;
+    pub const BLACK: Color = Color::new(0, 0, 0, 1.0);
+    pub const WHITE: Color = Color::new(255, 255, 255, 1.0);
+}
+";
+
+pub fn new_project(name: &str) {
+    let root = Path::new(name);
+    if root.exists() {
+        panic!("{} already exists", root.display());
+    }
+    let title = root
+        .file_name()
+        .and_then(|file_name| file_name.to_str())
+        .unwrap_or(name);
+    let fill = |template: &str| {
+        template
+            .replace("{name}", &title.replace(['_', ' '], "-").to_lowercase())
+            .replace("{title}", title)
+    };
+
+    let files = [
+        ("Cargo.toml", fill(CARGO_TOML)),
+        (".gitignore", GITIGNORE.to_string()),
+        ("src/main.rs", fill(MAIN)),
+        ("src/custom/mod.rs", CUSTOM_MOD.to_string()),
+        ("src/custom/api.rs", CUSTOM_API.to_string()),
+        ("src/custom/datatypes.rs", CUSTOM_DATATYPES.to_string()),
+        ("src/custom/components.rs", CUSTOM_COMPONENTS.to_string()),
+        ("src/custom/pages.rs", fill(CUSTOM_PAGES)),
+        ("src/custom/routes.rs", CUSTOM_ROUTES.to_string()),
+        ("src/custom/theme.rs", CUSTOM_THEME.to_string()),
+    ];
+    FRAMEWORK_FILES
+        .iter()
+        .map(|(path, contents)| (*path, contents.to_string()))
+        .chain(files)
+        .for_each(|(path, contents)| write_file(&root.join(path), &contents));
+    fs::create_dir_all(root.join(".public")).expect("Failed to create output directory");
+
+    println!("Created {name}, run `cargo run -- build` inside it to build the site");
+}
+
+// `music` adds a static route, `music/song_` adds the dynamic route `music/:song`
+pub fn add_route(route: &str) {
+    let segments: Vec<&str> = route.trim_matches('/').split('/').collect();
+    let is_dynamic = segments.last().is_some_and(|segment| segment.ends_with('_'));
+    let name = segments
+        .iter()
+        .map(|segment| segment.trim_end_matches('_').replace('-', "_").to_lowercase())
+        .collect::<Vec<String>>()
+        .join("_");
+    let constant = name.to_uppercase();
+    let title = segments
+        .last()
+        .map(|segment| segment.trim_end_matches('_'))
+        .unwrap_or(route);
+
+    let (route_code, page_code, registration) = if is_dynamic {
+        let pattern = segments
+            .iter()
+            .map(|segment| match segment.strip_suffix('_') {
+                Some(parameter) => format!(":{parameter}"),
+                None => segment.to_string(),
+            })
+            .collect::<Vec<String>>()
+            .join("/");
+        (
+            format!(
+                "
+pub const {constant}: &str = \"{pattern}\";
+
+pub fn {name}(slug: &str) -> crate::route::Route {{
+    crate::route::Route::dynamic({constant}, slug)
+}}
+"
+            ),
+            format!(
+                "
+pub fn {name}<T: crate::route::Slug>(item: &T, route: crate::route::Route) -> crate::site::Page {{
+    let mut page = crate::site::Page::new(item.slug(), route, super::datatypes::PageData::None);
+    page.push(super::components::page_template(crate::ui::heading(
+        crate::ui::HeadingLevel::H1,
+        item.slug(),
+    )));
+    page
+}}
+"
+            ),
+            format!("site.add_dynamic_pages(routes::{constant}, <your items>.iter(), pages::{name});"),
+        )
+    } else {
+        (
+            format!(
+                "
+pub const {constant}: crate::route::Route = crate::route::Route::Static(\"{}\");
+",
+                segments.join("/")
+            ),
+            format!(
+                "
+pub fn {name}() -> crate::site::Page {{
+    let mut page = crate::site::Page::new(\"{title}\", super::routes::{constant}, super::datatypes::PageData::None);
+    page.push(super::components::page_template(crate::ui::heading(
+        crate::ui::HeadingLevel::H1,
+        \"{title}\",
+    )));
+    page
+}}
+"
+            ),
+            format!("site.add_page(pages::{name}());"),
+        )
+    };
+
+    append_to_file(Path::new("src/custom/routes.rs"), &route_code);
+    append_to_file(Path::new("src/custom/pages.rs"), &page_code);
+    println!("Added route '{route}', register it in main.rs with:\n    {registration}");
+}
+
+fn write_file(path: &Path, contents: &str) {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).expect("Failed to create project directory");
+    }
+    fs::write(path, contents).unwrap_or_else(|_| panic!("Failed to write {}", path.display()));
+}
+
+fn append_to_file(path: &Path, contents: &str) {
+    let existing = fs::read_to_string(path).unwrap_or_else(|_| {
+        panic!(
+            "Failed to read {}, run `pages add` from the root of a pages project",
+            path.display()
+        )
+    });
+    fs::write(path, existing + contents)
+        .unwrap_or_else(|_| panic!("Failed to write {}", path.display()));
+}
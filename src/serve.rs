@@ -0,0 +1,220 @@
+use std::{
+    fs,
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+    path::{Path, PathBuf},
+    process::Command,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::{Duration, SystemTime},
+};
+
+const LIVE_RELOAD_PATH: &str = "/__live-reload";
+
+// only added to pages by Site::publish_dev, it waits for the server to say the site has been rebuilt
+pub const LIVE_RELOAD_SCRIPT: &str = "<script>new EventSource(\"/__live-reload\").onmessage = () => location.reload();</script>";
+
+// the sources are recompiled, and the data is refetched, whenever anything in these changes
+const WATCHED_PATHS: &[&str] = &["src", "data", ".env", "Cargo.toml"];
+
+pub fn serve(root: &Path, base_path: &str, port: u16) {
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .unwrap_or_else(|_| panic!("Failed to bind to port {port}"));
+    println!("Serving {} at http://localhost:{port}/{base_path}", root.display());
+
+    // bumped after every successful rebuild, each open page is waiting for it to change
+    let build_count = Arc::new(AtomicUsize::new(0));
+    let watcher_build_count = build_count.clone();
+    thread::spawn(move || watch(&watcher_build_count));
+
+    for stream in listener.incoming().flatten() {
+        let root = root.to_path_buf();
+        let base_path = base_path.to_string();
+        let build_count = build_count.clone();
+        thread::spawn(move || handle_connection(stream, &root, &base_path, &build_count));
+    }
+}
+
+fn watch(build_count: &AtomicUsize) {
+    let mut last_modified = latest_modification();
+    loop {
+        thread::sleep(Duration::from_millis(500));
+        let modified = latest_modification();
+        if modified == last_modified {
+            continue;
+        }
+        last_modified = modified;
+
+        println!("Changes detected, rebuilding...");
+        // the site is built by this binary, so changes to the sources need a fresh one
+        let rebuilt = Command::new("cargo")
+            .args(["run", "--quiet", "--", "build", "--dev"])
+            .status()
+            .is_ok_and(|status| status.success());
+        if rebuilt {
+            build_count.fetch_add(1, Ordering::SeqCst);
+            println!("Rebuilt site, reloading pages");
+        } else {
+            println!("Failed to rebuild site, waiting for further changes");
+        }
+    }
+}
+
+fn latest_modification() -> Option<SystemTime> {
+    WATCHED_PATHS
+        .iter()
+        .filter_map(|path| latest_modification_in(Path::new(path)))
+        .max()
+}
+
+fn latest_modification_in(path: &Path) -> Option<SystemTime> {
+    let modified = fs::metadata(path).and_then(|metadata| metadata.modified()).ok();
+    if !path.is_dir() {
+        return modified;
+    }
+    fs::read_dir(path)
+        .ok()?
+        .flatten()
+        .filter_map(|entry| latest_modification_in(&entry.path()))
+        .chain(modified)
+        .max()
+}
+
+fn handle_connection(mut stream: TcpStream, root: &Path, base_path: &str, build_count: &AtomicUsize) {
+    let mut request_line = String::new();
+    if BufReader::new(&stream).read_line(&mut request_line).is_err() {
+        return;
+    }
+    let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+
+    if path == LIVE_RELOAD_PATH {
+        return wait_for_rebuild(stream, build_count);
+    }
+
+    let response = match resolve(root, base_path, path).and_then(|file| Some((fs::read(&file).ok()?, file))) {
+        Some((body, file)) => response("200 OK", content_type(&file), body),
+        None => response("404 Not Found", "text/plain", b"Not Found".to_vec()),
+    };
+    let _ = stream.write_all(&response);
+}
+
+// holds an event stream open until the next rebuild, the keep-alive comments let us notice closed pages
+fn wait_for_rebuild(mut stream: TcpStream, build_count: &AtomicUsize) {
+    let current = build_count.load(Ordering::SeqCst);
+    let headers =
+        "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\n\r\n";
+    if stream.write_all(headers.as_bytes()).is_err() {
+        return;
+    }
+    loop {
+        thread::sleep(Duration::from_millis(250));
+        if build_count.load(Ordering::SeqCst) != current {
+            let _ = stream.write_all(b"data: reload\n\n");
+            return;
+        }
+        if stream.write_all(b":\n\n").is_err() {
+            return;
+        }
+    }
+}
+
+// maps a request path onto a file, so that "/" serves index.html and "/testimonials" serves testimonials.html
+fn resolve(root: &Path, base_path: &str, path: &str) -> Option<PathBuf> {
+    let path = path.split(['?', '#']).next().unwrap_or("/").trim_matches('/');
+    let path = path.strip_prefix(base_path)?;
+    if !path.is_empty() && !base_path.is_empty() && !path.starts_with('/') {
+        return None;
+    }
+    let path = path.trim_start_matches('/');
+    if path.split('/').any(|segment| segment == "..") {
+        return None;
+    }
+    let file = root.join(path);
+    let mut candidates = vec![file.join("index.html")];
+    if !path.is_empty() {
+        // appended rather than set with with_extension, which would cut a slug like "j.doe" at its dot
+        let mut html = file.clone().into_os_string();
+        html.push(".html");
+        candidates.extend([PathBuf::from(html), file]);
+    }
+    candidates.into_iter().find(|candidate| candidate.is_file())
+}
+
+fn content_type(file: &Path) -> &'static str {
+    match file.extension().and_then(|extension| extension.to_str()) {
+        Some("html") => "text/html; charset=utf-8",
+        Some("css") => "text/css",
+        Some("js") => "text/javascript",
+        Some("svg") => "image/svg+xml",
+        Some("png") => "image/png",
+        Some("jpg") | Some("jpeg") => "image/jpeg",
+        Some("ttf") => "font/ttf",
+        Some("woff2") => "font/woff2",
+        Some("xml") => "application/xml",
+        _ => "application/octet-stream",
+    }
+}
+
+fn response(status: &str, content_type: &str, body: Vec<u8>) -> Vec<u8> {
+    let mut response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        body.len()
+    )
+    .into_bytes();
+    response.extend(body);
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a fresh output directory with a home page, a section index, a page and a page whose slug has a dot
+    fn site(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("pages-serve-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        for file in ["index.html", "projects/index.html", "testimonials.html", "testimonials/j.doe.html", "style.css"] {
+            let file = root.join(file);
+            fs::create_dir_all(file.parent().unwrap()).unwrap();
+            fs::write(file, "").unwrap();
+        }
+        root
+    }
+
+    #[test]
+    fn serves_index_html_for_directories() {
+        let root = site("index");
+        assert_eq!(resolve(&root, "", "/"), Some(root.join("index.html")));
+        assert_eq!(resolve(&root, "", "/?live=1"), Some(root.join("index.html")));
+        assert_eq!(resolve(&root, "", "/projects/"), Some(root.join("projects/index.html")));
+    }
+
+    #[test]
+    fn serves_pages_without_their_extension() {
+        let root = site("extension");
+        assert_eq!(resolve(&root, "", "/testimonials"), Some(root.join("testimonials.html")));
+        assert_eq!(resolve(&root, "", "/testimonials/j.doe"), Some(root.join("testimonials/j.doe.html")));
+        assert_eq!(resolve(&root, "", "/style.css"), Some(root.join("style.css")));
+        assert_eq!(resolve(&root, "", "/missing"), None);
+    }
+
+    #[test]
+    fn strips_the_base_path() {
+        let root = site("base");
+        assert_eq!(resolve(&root, "blog", "/blog"), Some(root.join("index.html")));
+        assert_eq!(resolve(&root, "blog", "/blog/testimonials"), Some(root.join("testimonials.html")));
+        assert_eq!(resolve(&root, "blog", "/testimonials"), None);
+        assert_eq!(resolve(&root, "blog", "/blogtestimonials"), None);
+    }
+
+    #[test]
+    fn rejects_paths_out_of_the_output_directory() {
+        let root = site("parent");
+        assert_eq!(resolve(&root, "", "/../index.html"), None);
+        assert_eq!(resolve(&root, "", "/projects/../../index.html"), None);
+        assert_eq!(resolve(&root, "blog", "/blog/../index.html"), None);
+    }
+}
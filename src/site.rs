@@ -1,13 +1,22 @@
 use std::{
-    fs::{self, remove_file, OpenOptions},
-    io::Write,
+    collections::{BTreeMap, BTreeSet},
+    fs,
     path::{Path, PathBuf},
 };
 
+use chrono::{DateTime, Utc};
+
 use crate::{
     custom::datatypes::PageData,
-    html::{HtmlElement, Tag},
-    ui::{Element, ElementContent, HeadingLevel, Style},
+    feed::Feed,
+    font::{FontFamily, FontFile},
+    head::Meta,
+    html::{escape_attribute, escape_text, HtmlElement, Stylesheet},
+    route::{under_base_path, Route, Slug},
+    serve::LIVE_RELOAD_SCRIPT,
+    sitemap::{self, Robots},
+    theme::{Theme, ThemeCondition},
+    ui::{Breakpoints, Element, Keyframes, Style},
 };
 
 pub struct Site {
@@ -15,6 +24,26 @@ pub struct Site {
     pub title: String,
     pub home: Page,
     pub global_styles: Vec<Style>,
+    // written to style.css as custom properties, see Theme, its breakpoints are the ones add_styles_at applies at
+    pub theme: Theme,
+    // the files of each family used with Style::Font, written to style.css as @font-face rules
+    pub fonts: Vec<(FontFamily, Vec<FontFile>)>,
+    pub alternative_themes: Vec<(ThemeCondition, Theme)>,
+    // by name, so each @keyframes block is written once
+    pub keyframes: BTreeMap<&'static str, Keyframes>,
+    // turns off every transition and animation for visitors who ask for less motion
+    pub reduced_motion: bool,
+    pub out_dir: PathBuf,
+    pub base_path: String,
+    pub assets_dir: Option<PathBuf>,
+    // the scheme and host the site is published at, e.g. "https://sashin.dev"
+    pub url: Option<String>,
+    // the head metadata of any page that doesn't set its own
+    pub meta: Meta,
+    // both need the site's url, see set_url
+    pub sitemap: bool,
+    pub robots: Option<Robots>,
+    pub feeds: Vec<Feed>,
 }
 
 impl Site {
@@ -24,6 +53,100 @@ impl Site {
             title: title.to_string(),
             home,
             global_styles: Vec::new(),
+            theme: Theme::default(),
+            fonts: Vec::new(),
+            alternative_themes: Vec::new(),
+            keyframes: BTreeMap::new(),
+            reduced_motion: false,
+            out_dir: PathBuf::from(".public"),
+            base_path: String::new(),
+            assets_dir: None,
+            url: None,
+            meta: Meta::new(),
+            sitemap: false,
+            robots: None,
+            feeds: Vec::new(),
+        }
+    }
+
+    // where the site is written to, ".public" unless set
+    pub fn set_output_dir(&mut self, out_dir: impl Into<PathBuf>) {
+        self.out_dir = out_dir.into();
+    }
+
+    // the path the site is hosted under, e.g. "/blog" when it is served from example.com/blog
+    pub fn set_base_path(&mut self, base_path: &str) {
+        self.base_path = base_path.trim_matches('/').to_string();
+    }
+
+    // a directory of images, fonts etc. that is copied to <out>/assets when publishing
+    pub fn set_assets_dir(&mut self, assets_dir: impl Into<PathBuf>) {
+        self.assets_dir = Some(assets_dir.into());
+    }
+
+    pub fn set_url(&mut self, url: &str) {
+        self.url = Some(url.trim_end_matches('/').to_string());
+    }
+
+    // the same as setting the theme's breakpoints
+    pub fn set_breakpoints(&mut self, breakpoints: Breakpoints) {
+        self.theme.breakpoints = breakpoints;
+    }
+
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+
+    // the paths are relative to the base path, so fonts in the assets dir are at "assets/fonts/..."
+    pub fn add_font(&mut self, family: FontFamily, files: &[FontFile]) {
+        self.fonts.push((family, files.to_vec()));
+    }
+
+    // e.g. a light version of the theme for visitors whose system prefers it, its breakpoints are ignored
+    pub fn add_alternative_theme(&mut self, when: ThemeCondition, theme: Theme) {
+        self.alternative_themes.push((when, theme));
+    }
+
+    // elements use them with Style::Animation(keyframes.animation(duration_ms))
+    pub fn add_keyframes(&mut self, keyframes: Keyframes) {
+        self.keyframes.insert(keyframes.name, keyframes);
+    }
+
+    pub fn respect_reduced_motion(&mut self) {
+        self.reduced_motion = true;
+    }
+
+    pub fn set_meta(&mut self, meta: Meta) {
+        self.meta = meta;
+    }
+
+    // writes sitemap.xml with every page that hasn't opted out of it
+    pub fn enable_sitemap(&mut self) {
+        self.sitemap = true;
+    }
+
+    pub fn set_robots(&mut self, robots: Robots) {
+        self.robots = Some(robots);
+    }
+
+    // the feed is written alongside the pages, and every page links to it
+    pub fn add_feed(&mut self, feed: Feed) {
+        self.feeds.push(feed);
+    }
+
+    // the absolute url of a route, only known once the site's url is set
+    pub fn page_url(&self, route: &Route) -> Option<String> {
+        self.url
+            .as_ref()
+            .map(|url| format!("{url}{}{}", self.base_href(), route.path()))
+    }
+
+    // what every path on the site is written under, "/" unless the site has a base path
+    pub fn base_href(&self) -> String {
+        if self.base_path.is_empty() {
+            "/".to_string()
+        } else {
+            format!("/{}/", self.base_path)
         }
     }
 
@@ -36,25 +159,268 @@ impl Site {
     }
 
     pub fn publish(&self) {
-        Self::delete_css();
-        self.pages.publish();
-        self.home.publish();
+        self.publish_with(false);
+    }
+
+    // same as publish, but every page also gets the script that reloads it when `pages serve` rebuilds the site
+    pub fn publish_dev(&self) {
+        self.publish_with(true);
+    }
+
+    fn publish_with(&self, live_reload: bool) {
+        self.check_animations();
+        self.check_tokens();
+        fs::create_dir_all(&self.out_dir).expect("Failed to create output directory");
+        self.copy_assets();
+        self.write_css();
+        self.write_sitemap();
+        self.write_robots();
+        self.write_feeds();
+        self.pages.publish(self, live_reload);
+        self.home.publish(self, live_reload);
+    }
+
+    // an animation naming keyframes that were never registered would silently do nothing, so the build fails instead
+    fn check_animations(&self) {
+        let missing = self
+            .all_pages()
+            .flat_map(|page| {
+                let stylesheet = page.stylesheet();
+                stylesheet
+                    .styles()
+                    .chain(&page.styles)
+                    .filter_map(|style| match style {
+                        Style::Animation(animation) => Some(animation.name),
+                        _ => None,
+                    })
+                    .filter(|name| !self.keyframes.contains_key(name))
+                    .collect::<Vec<&'static str>>()
+            })
+            .collect::<BTreeSet<&'static str>>();
+        assert!(
+            missing.is_empty(),
+            "Animations use keyframes that aren't registered with Site::add_keyframes: {}",
+            missing.into_iter().collect::<Vec<&str>>().join(", ")
+        );
+    }
+
+    // a token no theme defines would leave its var() pointing at nothing, so the build fails instead
+    // alternatives only apply to some visitors, so only the site's theme counts and the alternatives can't add tokens
+    fn check_tokens(&self) {
+        let defined = self
+            .theme
+            .tokens()
+            .into_iter()
+            .collect::<BTreeSet<String>>();
+        let added = self
+            .alternative_themes
+            .iter()
+            .flat_map(|(_, theme)| theme.tokens())
+            .filter(|token| !defined.contains(token))
+            .collect::<BTreeSet<String>>();
+        assert!(
+            added.is_empty(),
+            "Alternative themes define tokens that the site's theme doesn't: {}",
+            added.into_iter().collect::<Vec<String>>().join(", ")
+        );
+        let missing = self
+            .css_files()
+            .into_iter()
+            .map(|(_, css)| css)
+            .chain(
+                self.all_pages()
+                    .map(|page| page.get_inline_style_string(self)),
+            )
+            .flat_map(|css| {
+                css.split("var(--")
+                    .skip(1)
+                    .filter_map(|rest| rest.split_once(')').map(|(token, _)| token.to_string()))
+                    .collect::<Vec<String>>()
+            })
+            .filter(|token| !defined.contains(token))
+            .collect::<BTreeSet<String>>();
+        assert!(
+            missing.is_empty(),
+            "Styles use tokens that the site's theme doesn't define: {}",
+            missing.into_iter().collect::<Vec<String>>().join(", ")
+        );
     }
 
-    pub fn delete_css() {
-        let path = ".public/style.css";
+    pub fn all_pages(&self) -> impl Iterator<Item = &Page> {
+        std::iter::once(&self.home).chain(self.pages.0.iter())
+    }
+
+    // the rules of every page that doesn't have a stylesheet of its own, each written once
+    pub fn stylesheet(&self) -> Stylesheet {
+        self.all_pages()
+            .filter(|page| !page.own_stylesheet)
+            .fold(Stylesheet::new(), |stylesheet, page| {
+                stylesheet.merge(page.stylesheet())
+            })
+    }
+
+    // style.css and the stylesheet of each page that has its own, relative to the output directory. Bundles are written
+    // next to their page's path, so urls in them are put under the base path rather than left relative to the bundle
+    fn css_files(&self) -> Vec<(PathBuf, String)> {
+        let shared = self.stylesheet();
+        let breakpoints = &self.theme.breakpoints;
+        let base_href = self.base_href();
+        let files = std::iter::once((
+            PathBuf::from("style.css"),
+            self.font_css()
+                + &self.theme_css()
+                + &shared
+                    .clone()
+                    .under_base_path(&base_href)
+                    .to_css(breakpoints)
+                + &self.motion_css(),
+        ))
+        .chain(
+            self.all_pages()
+                .filter(|page| page.own_stylesheet)
+                .map(|page| {
+                    (
+                        page.stylesheet_path(),
+                        page.stylesheet()
+                            .without(&shared)
+                            .under_base_path(&base_href)
+                            .to_css(breakpoints),
+                    )
+                }),
+        )
+        .collect::<Vec<(PathBuf, String)>>();
+        let mut paths = BTreeSet::new();
+        files.iter().for_each(|(path, _)| {
+            assert!(
+                paths.insert(path),
+                "Two stylesheets would be written to {}",
+                path.display()
+            )
+        });
+        files
+    }
+
+    fn write_css(&self) {
+        self.css_files()
+            .into_iter()
+            .for_each(|(path, css)| write_atomically(&self.out_dir.join(path), &css));
+        println!("Successfully published css");
+    }
+
+    fn font_css(&self) -> String {
+        self.fonts
+            .iter()
+            .flat_map(|(family, files)| {
+                files
+                    .iter()
+                    .map(move |file| file.to_css(family, &self.base_href()) + "\n")
+            })
+            .collect()
+    }
+
+    // before the rules, so the alternative themes override the site's one and the rules can use both
+    fn theme_css(&self) -> String {
+        std::iter::once(self.theme.to_css())
+            .chain(
+                self.alternative_themes
+                    .iter()
+                    .map(|(when, theme)| theme.to_alternative_css(when)),
+            )
+            .filter(|css| !css.is_empty())
+            .map(|css| css + "\n")
+            .collect()
+    }
 
-        if Path::new(&path).exists() {
-            remove_file(path).expect("Failed to remove file from {path}");
-            println!("File deleted.");
+    // every page links style.css, so the keyframes only need to be in there
+    fn motion_css(&self) -> String {
+        let keyframes = self
+            .keyframes
+            .values()
+            .map(|keyframes| format!("\n{}", keyframes.to_css()))
+            .collect::<String>();
+        if self.reduced_motion {
+            keyframes
+                + "\n@media (prefers-reduced-motion:reduce){*,*::before,*::after{animation:none !important;transition:none !important;}}"
         } else {
-            println!("File does not exist, skipping.");
+            keyframes
+        }
+    }
+
+    fn sitemap_url(&self) -> Option<String> {
+        if !self.sitemap {
+            return None;
+        }
+        self.url
+            .as_ref()
+            .map(|url| format!("{url}{}sitemap.xml", self.base_href()))
+    }
+
+    fn write_sitemap(&self) {
+        if !self.sitemap {
+            return;
+        }
+        let entries = self
+            .all_pages()
+            .filter(|page| page.in_sitemap && !page.noindex)
+            .map(|page| {
+                let url = self
+                    .page_url(&page.route)
+                    .expect("The sitemap needs the site's url, set it with Site::set_url");
+                (url, page.last_modified)
+            })
+            .collect::<Vec<(String, Option<DateTime<Utc>>)>>();
+        write_atomically(
+            &self.out_dir.join("sitemap.xml"),
+            &sitemap::to_xml(&entries),
+        );
+        println!("Successfully published sitemap");
+    }
+
+    fn write_robots(&self) {
+        let Some(robots) = &self.robots else {
+            return;
         };
+        write_atomically(
+            &self.out_dir.join("robots.txt"),
+            &robots.to_txt(&self.base_href(), self.sitemap_url().as_deref()),
+        );
+        println!("Successfully published robots.txt");
+    }
+
+    fn write_feeds(&self) {
+        self.feeds.iter().for_each(|feed| {
+            write_atomically(&self.out_dir.join(feed.rss_path()), &feed.to_rss(self));
+            write_atomically(&self.out_dir.join(feed.atom_path()), &feed.to_atom(self));
+            println!("Successfully published feed '{}'", feed.title);
+        });
+    }
+
+    fn copy_assets(&self) {
+        let Some(assets_dir) = &self.assets_dir else {
+            return;
+        };
+        let destination = self.out_dir.join("assets");
+        if fs::canonicalize(assets_dir).ok() == fs::canonicalize(&destination).ok() {
+            return;
+        }
+        copy_dir(assets_dir, &destination);
     }
 
     pub fn add_page(&mut self, page: Page) {
         self.pages.0.push(page);
     }
+
+    // adds a page for every item in a collection, filling the dynamic route's pattern with the item's slug
+    pub fn add_dynamic_pages<'a, T: Slug + 'a>(
+        &mut self,
+        pattern: &'static str,
+        items: impl IntoIterator<Item = &'a T>,
+        view: impl Fn(&T, Route) -> Page,
+    ) {
+        items.into_iter().for_each(|item| {
+            self.add_page(view(item, Route::dynamic(pattern, item.slug())));
+        });
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -70,17 +436,14 @@ impl Pages {
         self.clone()
     }
 
-    pub fn write_html(&self) {
-        self.0.iter().for_each(|page| page.write_html());
+    pub fn write_html(&self, site: &Site, live_reload: bool) {
+        self.0
+            .iter()
+            .for_each(|page| page.write_html(site, live_reload));
     }
 
-    //this runs for each page, you will need to fix this when you have more pages
-    pub fn write_css(&self) {
-        self.0.iter().for_each(|page| page.write_css());
-    }
-    pub fn publish(&self) {
-        self.write_html();
-        self.write_css();
+    pub fn publish(&self, site: &Site, live_reload: bool) {
+        self.write_html(site, live_reload);
         println!("Successfully published site");
     }
 }
@@ -90,48 +453,86 @@ pub struct Page {
     pub title: String,
     pub styles: Vec<Style>,
     pub content: Vec<Element>,
-    pub path: PathBuf,
+    pub route: Route,
     pub data: PageData,
+    // rules only this page uses go in a stylesheet next to it instead of the shared one
+    pub own_stylesheet: bool,
+    // anything left unset falls back to the site's meta
+    pub meta: Meta,
+    // the sitemap's lastmod for this page
+    pub last_modified: Option<DateTime<Utc>>,
+    pub in_sitemap: bool,
+    // asks search engines not to index the page, which also leaves it out of the sitemap
+    pub noindex: bool,
 }
 
 impl Page {
-    pub fn new(title: &str, path: &str, data: PageData) -> Self {
+    pub fn new(title: &str, route: Route, data: PageData) -> Self {
         Self {
             title: title.to_string(),
             styles: Vec::new(),
             content: Vec::new(),
-            path: PathBuf::from(path),
+            route,
             data,
+            own_stylesheet: false,
+            meta: Meta::new(),
+            last_modified: None,
+            in_sitemap: true,
+            noindex: false,
         }
     }
 
-    fn write_html(&self) {
-        fs::write(&self.path, self.to_html()).expect(&format!(
-            "Failed to write document to {}",
-            &self.path.display()
-        ));
+    pub fn with_own_stylesheet(mut self) -> Self {
+        self.own_stylesheet = true;
+        self
+    }
+
+    pub fn with_meta(mut self, meta: Meta) -> Self {
+        self.meta = meta;
+        self
+    }
+
+    pub fn with_last_modified(mut self, last_modified: Option<DateTime<Utc>>) -> Self {
+        self.last_modified = last_modified;
+        self
+    }
+
+    pub fn without_sitemap(mut self) -> Self {
+        self.in_sitemap = false;
+        self
+    }
+
+    pub fn with_noindex(mut self) -> Self {
+        self.noindex = true;
+        self
+    }
+
+    // relative to the site's output directory, only written for pages with their own stylesheet. Bundles go in a
+    // directory of their own, so a page on a route like "style" can't overwrite the shared style.css
+    pub fn stylesheet_path(&self) -> PathBuf {
+        Path::new("css").join(self.path().with_extension("css"))
+    }
+
+    // relative to the site's output directory
+    pub fn path(&self) -> PathBuf {
+        self.route.file_path()
+    }
+
+    fn write_html(&self, site: &Site, live_reload: bool) {
+        let path = site.out_dir.join(self.path());
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("Failed to create directory for page");
+        }
+        fs::write(&path, self.to_html(site, live_reload))
+            .unwrap_or_else(|_| panic!("Failed to write document to {}", path.display()));
         println!(
             "Successfully published html for page '{}' to {:?}",
-            self.title, self.path
+            self.title, path
         );
     }
 
-    fn write_css(&self) {
-        let css = self.get_css();
-        let mut file = OpenOptions::new()
-            .write(true)
-            .append(true)
-            .create(true)
-            .open(".public/style.css")
-            .expect("Failed to create or open file");
-        // fs::write(".public/style.css", css).expect(&format!("Failed to write stylesheet"));
-        writeln!(file, "{}", css).expect("Failed to write to css file");
-        println!("Successfully published css for page {}", self.title);
-    }
-
-    pub fn publish(&self) {
-        self.write_html();
-        self.write_css();
+    pub fn publish(&self, site: &Site, live_reload: bool) {
+        self.write_html(site, live_reload);
     }
 
     pub fn push(&mut self, element: Element) -> Self {
@@ -144,55 +545,80 @@ impl Page {
         self
     }
 
-    fn get_inline_style_string(&self) -> String {
+    fn get_inline_style_string(&self, site: &Site) -> String {
         self.styles
             .iter()
-            .map(|style| style.to_string())
+            .map(|style| style.clone().under_base_path(&site.base_href()).to_string())
             .collect::<Vec<String>>()
             .join("")
     }
 
-    fn get_css(&self) -> String {
-        self.content.iter().fold("".to_string(), |output, element| {
-            let tag: Tag = match &element.content {
-                ElementContent::Column(_) => Tag::Div,
-                ElementContent::Row(_) => Tag::Div,
-                ElementContent::Text(_) => Tag::Span,
-                ElementContent::Link(_) => Tag::A,
-                ElementContent::Image(_) => Tag::IMG,
-                ElementContent::Heading(heading) => match heading.level {
-                    HeadingLevel::H1 => Tag::H1,
-                    HeadingLevel::H2 => Tag::H2,
-                    HeadingLevel::H3 => Tag::H3,
-                    HeadingLevel::H4 => Tag::H4,
-                    HeadingLevel::H5 => Tag::H5,
-                    HeadingLevel::H6 => Tag::H6,
-                },
-            };
-            output + &HtmlElement::from_element(element, tag).write_css()
-        })
-    }
-
-    fn get_elements_html(&self) -> String {
-        let html = self.content.iter().fold("".to_string(), |output, element| {
-            let tag: Tag = match &element.content {
-                ElementContent::Column(_) => Tag::Div,
-                ElementContent::Row(_) => Tag::Div,
-                ElementContent::Text(_) => Tag::Span,
-                ElementContent::Link(_) => Tag::A,
-                ElementContent::Image(_) => Tag::IMG,
-                ElementContent::Heading(heading) => match heading.level {
-                    HeadingLevel::H1 => Tag::H1,
-                    HeadingLevel::H2 => Tag::H2,
-                    HeadingLevel::H3 => Tag::H3,
-                    HeadingLevel::H4 => Tag::H4,
-                    HeadingLevel::H5 => Tag::H5,
-                    HeadingLevel::H6 => Tag::H6,
-                },
-            };
-            output + &HtmlElement::from_element(element, tag).write_html()
-        });
-        html
+    // ids are generated from each element's position on the page, starting again for every page
+    fn html_elements(&self) -> Vec<HtmlElement> {
+        self.content
+            .iter()
+            .enumerate()
+            .map(|(index, element)| HtmlElement::from_element(element, element.get_tag(), &[index]))
+            .collect()
+    }
+
+    pub fn stylesheet(&self) -> Stylesheet {
+        self.html_elements()
+            .iter()
+            .fold(Stylesheet::new(), |stylesheet, element| {
+                stylesheet.merge(element.stylesheet())
+            })
+    }
+
+    fn get_stylesheet_links(&self, site: &Site) -> String {
+        let link = |path: &str| {
+            format!(
+                "<link rel=\"stylesheet\" type=\"text/css\" href=\"{}\">",
+                escape_attribute(&under_base_path(&site.base_href(), path))
+            )
+        };
+        if self.own_stylesheet {
+            link("style.css") + "\n    " + &link(&self.stylesheet_path().display().to_string())
+        } else {
+            link("style.css")
+        }
+    }
+
+    fn get_meta_html(&self, site: &Site) -> String {
+        let mut meta = self.meta.or(&site.meta);
+        meta.url = self.meta.url.clone().or(site.page_url(&self.route));
+        meta.favicon = meta
+            .favicon
+            .map(|favicon| under_base_path(&site.base_href(), &favicon));
+        let html = meta.to_html(&self.title, &site.title);
+        if self.noindex {
+            html + "\n    <meta name=\"robots\" content=\"noindex\">"
+        } else {
+            html
+        }
+    }
+
+    fn get_font_preloads(&self, site: &Site) -> String {
+        site.fonts
+            .iter()
+            .flat_map(|(_, files)| files.iter().filter(|file| file.preload))
+            .map(|file| file.preload_tag(&site.base_href()) + "\n    ")
+            .collect()
+    }
+
+    fn get_feed_links(&self, site: &Site) -> String {
+        site.feeds
+            .iter()
+            .map(|feed| format!("\n    {}", feed.link_tags(&site.base_href())))
+            .collect()
+    }
+
+    fn get_elements_html(&self, site: &Site) -> String {
+        self.html_elements()
+            .into_iter()
+            .fold("".to_string(), |output, element| {
+                output + &element.under_base_path(&site.base_href()).write_html()
+            })
     }
 
     pub fn add_style(mut self, style: Style) -> Self {
@@ -210,23 +636,165 @@ impl Page {
         self.clone()
     }
 
-    fn to_html(&self) -> String {
+    fn to_html(&self, site: &Site, live_reload: bool) -> String {
         format!(
             "<!DOCTYPE html>
 <html lang=\"en\">
 <head>
     <meta charset=\"UTF-8\">
     <title>{}</title>
+    {}
     <style>* {{box-sizing:border-box;}}</style>
-    <link rel=\"stylesheet\" type=\"text/css\" href=\"style.css\">
+    {}{}{}
 </head>
 <body style=\"box-sizing:border-box;{}\">
-{}
+{}{}
 </body>
 </html>",
-            self.title,
-            self.get_inline_style_string(),
-            self.get_elements_html()
+            escape_text(&self.title),
+            self.get_meta_html(site),
+            self.get_font_preloads(site),
+            self.get_stylesheet_links(site),
+            self.get_feed_links(site),
+            escape_attribute(&self.get_inline_style_string(site)),
+            self.get_elements_html(site),
+            if live_reload { LIVE_RELOAD_SCRIPT } else { "" }
+        )
+    }
+}
+
+// written next to its destination first, so a failed build never leaves half a file behind
+fn write_atomically(path: &Path, contents: &str) {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).expect("Failed to create directory");
+    }
+    let temporary = path.with_extension("tmp");
+    fs::write(&temporary, contents)
+        .unwrap_or_else(|_| panic!("Failed to write {}", temporary.display()));
+    fs::rename(&temporary, path)
+        .unwrap_or_else(|_| panic!("Failed to move {} into place", path.display()));
+}
+
+fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap_or_else(|_| panic!("Failed to create {}", to.display()));
+    fs::read_dir(from)
+        .unwrap_or_else(|_| panic!("Failed to read {}", from.display()))
+        .flatten()
+        .for_each(|entry| {
+            let destination = to.join(entry.file_name());
+            if entry.path().is_dir() {
+                copy_dir(&entry.path(), &destination);
+            } else {
+                fs::copy(entry.path(), &destination)
+                    .unwrap_or_else(|_| panic!("Failed to copy {}", entry.path().display()));
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ui::{column, Background, Unit};
+
+    fn site_using(style: Style, theme: Theme) -> Site {
+        let home = Page::new("Home", Route::Home, PageData::None)
+            .push_elements(vec![column().add_style(style)]);
+        let mut site = Site::new(home, "Test");
+        site.set_theme(theme);
+        site
+    }
+
+    #[test]
+    fn tokens_the_theme_defines_pass() {
+        site_using(
+            Style::Padding(Unit::Space("m")),
+            Theme::new().space("m", Unit::Px(10)),
+        )
+        .check_tokens();
+    }
+
+    #[test]
+    #[should_panic(expected = "space-l")]
+    fn tokens_no_theme_defines_fail_the_build() {
+        site_using(
+            Style::Padding(Unit::Space("l")),
+            Theme::new().space("m", Unit::Px(10)),
         )
+        .check_tokens();
+    }
+
+    #[test]
+    #[should_panic(expected = "font-size-m")]
+    fn inline_page_styles_are_checked_too() {
+        let mut site = site_using(Style::Padding(Unit::Px(0)), Theme::new());
+        site.home = site.home.add_style(Style::FontSize(Unit::FontSize("m")));
+        site.check_tokens();
+    }
+
+    #[test]
+    fn alternative_themes_can_change_tokens() {
+        let mut site = site_using(
+            Style::Padding(Unit::Space("m")),
+            Theme::new().space("m", Unit::Px(10)),
+        );
+        site.add_alternative_theme(
+            ThemeCondition::PrefersDark,
+            Theme::new().space("m", Unit::Px(20)),
+        );
+        site.check_tokens();
+    }
+
+    #[test]
+    #[should_panic(expected = "space-m")]
+    fn alternative_themes_cannot_add_tokens() {
+        let mut site = site_using(Style::Padding(Unit::Space("m")), Theme::new());
+        site.add_alternative_theme(
+            ThemeCondition::PrefersDark,
+            Theme::new().space("m", Unit::Px(10)),
+        );
+        site.check_tokens();
+    }
+
+    #[test]
+    fn page_bundles_never_overwrite_the_shared_stylesheet() {
+        let mut site = site_using(Style::Padding(Unit::Px(1)), Theme::new());
+        site.pages.add(
+            Page::new("Style", Route::Static("style"), PageData::None)
+                .with_own_stylesheet()
+                .push_elements(vec![column().add_style(Style::Padding(Unit::Px(2)))]),
+        );
+        let paths = site
+            .css_files()
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect::<Vec<PathBuf>>();
+        assert_eq!(
+            paths,
+            vec![PathBuf::from("style.css"), PathBuf::from("css/style.css")]
+        );
+    }
+
+    #[test]
+    fn urls_in_page_bundles_are_under_the_base_path() {
+        let mut site = site_using(Style::Padding(Unit::Px(1)), Theme::new());
+        site.set_base_path("blog");
+        site.pages.add(
+            Page::new(
+                "Project",
+                Route::dynamic("projects/:slug", "pages"),
+                PageData::None,
+            )
+            .with_own_stylesheet()
+            .push_elements(vec![column().add_style(Style::BackgroundImage(vec![
+                Background::url("assets/screenshot.png"),
+                Background::url("https://example.com/a.png"),
+            ]))]),
+        );
+        let (path, css) = site.css_files().pop().unwrap();
+        assert_eq!(path, PathBuf::from("css/projects/pages.css"));
+        assert!(
+            css.contains("url(\"/blog/assets/screenshot.png\"),url(\"https://example.com/a.png\")"),
+            "{css}"
+        );
     }
 }
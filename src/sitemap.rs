@@ -0,0 +1,112 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::html::escape_attribute;
+
+// each page's absolute url, with the time it last changed when that is known
+pub fn to_xml(entries: &[(String, Option<DateTime<Utc>>)]) -> String {
+    let urls = entries
+        .iter()
+        .map(|(url, last_modified)| {
+            let last_modified = last_modified
+                .map(|date| {
+                    format!(
+                        "<lastmod>{}</lastmod>",
+                        date.to_rfc3339_opts(SecondsFormat::Secs, true)
+                    )
+                })
+                .unwrap_or_default();
+            format!(
+                "  <url><loc>{}</loc>{last_modified}</url>",
+                escape_attribute(url)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>
+<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">
+{urls}
+</urlset>
+"
+    )
+}
+
+// the rules written to robots.txt, e.g. Robots::new().user_agent("*").disallow("drafts")
+#[derive(Debug, Clone, Default)]
+pub struct Robots {
+    groups: Vec<RobotsGroup>,
+}
+
+#[derive(Debug, Clone)]
+struct RobotsGroup {
+    user_agent: String,
+    allow: Vec<String>,
+    disallow: Vec<String>,
+}
+
+impl Robots {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // every crawler may index every page
+    pub fn allow_all() -> Self {
+        Self::new().user_agent("*")
+    }
+
+    // starts a group of rules, the allows and disallows after it apply to this user agent
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.groups.push(RobotsGroup {
+            user_agent: user_agent.to_string(),
+            allow: Vec::new(),
+            disallow: Vec::new(),
+        });
+        self
+    }
+
+    // paths are relative to the site's base path, like routes
+    pub fn allow(mut self, path: &str) -> Self {
+        self.current_group().allow.push(path.to_string());
+        self
+    }
+
+    pub fn disallow(mut self, path: &str) -> Self {
+        self.current_group().disallow.push(path.to_string());
+        self
+    }
+
+    fn current_group(&mut self) -> &mut RobotsGroup {
+        self.groups
+            .last_mut()
+            .expect("Robots rules need a user_agent before any allow or disallow")
+    }
+
+    pub fn to_txt(&self, base_href: &str, sitemap_url: Option<&str>) -> String {
+        let path = |path: &String| format!("{base_href}{}", path.trim_start_matches('/'));
+        let groups = self.groups.iter().map(|group| {
+            let mut lines = vec![format!("User-agent: {}", group.user_agent)];
+            lines.extend(
+                group
+                    .allow
+                    .iter()
+                    .map(|allow| format!("Allow: {}", path(allow))),
+            );
+            lines.extend(
+                group
+                    .disallow
+                    .iter()
+                    .map(|disallow| format!("Disallow: {}", path(disallow))),
+            );
+            // an empty disallow is how robots.txt says nothing is off limits
+            if group.allow.is_empty() && group.disallow.is_empty() {
+                lines.push("Disallow:".to_string());
+            }
+            lines.join("\n")
+        });
+        groups
+            .chain(sitemap_url.map(|url| format!("Sitemap: {url}")))
+            .collect::<Vec<String>>()
+            .join("\n\n")
+            + "\n"
+    }
+}
@@ -0,0 +1,118 @@
+use std::collections::BTreeMap;
+
+use crate::ui::{Breakpoints, Color, Unit};
+
+// The design tokens of a site, written once to its stylesheet as custom properties on :root. Styles refer to them with
+// colours made with Color::as_token and with Unit::Space, Unit::FontSize and Unit::Radius, so swapping the theme
+// restyles every page without touching the rules.
+#[derive(Debug, Clone, Default)]
+pub struct Theme {
+    pub colors: BTreeMap<&'static str, Color>,
+    pub spacing: BTreeMap<&'static str, Unit>,
+    pub font_sizes: BTreeMap<&'static str, Unit>,
+    pub radii: BTreeMap<&'static str, Unit>,
+    pub breakpoints: Breakpoints,
+}
+
+impl Theme {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // the colour has to have been given a token name with Color::as_token
+    pub fn color(mut self, color: Color) -> Self {
+        let name = color.token().unwrap_or_else(|| {
+            panic!("Theme colours need a token name, {color:?} doesn't have one")
+        });
+        self.colors.insert(name, color);
+        self
+    }
+
+    pub fn space(mut self, name: &'static str, size: Unit) -> Self {
+        self.spacing.insert(name, size);
+        self
+    }
+
+    pub fn font_size(mut self, name: &'static str, size: Unit) -> Self {
+        self.font_sizes.insert(name, size);
+        self
+    }
+
+    pub fn radius(mut self, name: &'static str, size: Unit) -> Self {
+        self.radii.insert(name, size);
+        self
+    }
+
+    pub fn breakpoints(mut self, breakpoints: Breakpoints) -> Self {
+        self.breakpoints = breakpoints;
+        self
+    }
+
+    // the name and value of each custom property, without the leading "--"
+    fn properties(&self) -> Vec<(String, String)> {
+        let colors = self
+            .colors
+            .iter()
+            .map(|(name, color)| (format!("color-{name}"), color.rgba()));
+        let units = [
+            ("space", &self.spacing),
+            ("font-size", &self.font_sizes),
+            ("radius", &self.radii),
+        ]
+        .into_iter()
+        .flat_map(|(kind, tokens)| {
+            tokens
+                .iter()
+                .map(move |(name, size)| (format!("{kind}-{name}"), size.to_string()))
+        });
+        colors.chain(units).collect()
+    }
+
+    // e.g. "color-rich-black" and "space-m", what styles refer to with var(--...)
+    pub fn tokens(&self) -> Vec<String> {
+        self.properties()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    fn custom_properties(&self) -> String {
+        self.properties()
+            .into_iter()
+            .map(|(name, value)| format!("--{name}:{value};"))
+            .collect()
+    }
+
+    // nothing for a theme without tokens, e.g. one only set for its breakpoints
+    pub fn to_css(&self) -> String {
+        if self.properties().is_empty() {
+            return String::new();
+        }
+        format!(":root{{{}}}", self.custom_properties())
+    }
+
+    // an alternative only needs the tokens it changes, the rest come from the site's theme
+    pub fn to_alternative_css(&self, when: &ThemeCondition) -> String {
+        match when {
+            ThemeCondition::PrefersDark | ThemeCondition::PrefersLight => format!(
+                "@media (prefers-color-scheme:{}){{:root{{{}}}}}",
+                if matches!(when, ThemeCondition::PrefersDark) {
+                    "dark"
+                } else {
+                    "light"
+                },
+                self.custom_properties()
+            ),
+            ThemeCondition::Class(class) => format!(".{class}{{{}}}", self.custom_properties()),
+        }
+    }
+}
+
+// when an alternative theme replaces the site's one
+#[derive(Debug, Clone)]
+pub enum ThemeCondition {
+    PrefersDark,
+    PrefersLight,
+    // e.g. a "light" class toggled on <html> by a script
+    Class(&'static str),
+}
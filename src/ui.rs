@@ -1,5 +1,7 @@
-use std::{collections::HashMap, fmt::Debug};
-use crate::{id, html::Tag};
+use std::{collections::BTreeMap, fmt::Debug};
+use crate::font::FontFamily;
+use crate::html::{escape_text, Tag};
+use crate::route::under_base_path;
 
 #[macro_export]
 macro_rules! column {
@@ -32,10 +34,23 @@ pub struct PushError;
 
 #[derive(Debug, Clone)]
 pub struct Element {
-    pub id: String,
+    // only set when the user gives the element an id, otherwise one is derived from its position on the page
+    pub id: Option<String>,
     pub content: ElementContent,
     pub meta: ElementMetaData,
 }
+
+// an element without an id or styles yet
+impl From<ElementContent> for Element {
+    fn from(content: ElementContent) -> Self {
+        Element {
+            id: None,
+            content,
+            meta: ElementMetaData::new(),
+        }
+    }
+}
+
 impl Element {
     pub fn push(&mut self, element: Element) -> Self {
         match &mut self.content {
@@ -47,6 +62,35 @@ impl Element {
                 row.elements.push(element);
                 self.clone()
             }
+            ElementContent::Grid(grid) => {
+                grid.elements.push(element);
+                self.clone()
+            }
+            ElementContent::List(list) => {
+                list.elements.push(list.kind.item(element));
+                self.clone()
+            }
+            ElementContent::ListItem(item) => {
+                item.elements.push(element);
+                self.clone()
+            }
+            // rows go in the table's body, see push_header_row and push_footer_row for the others
+            ElementContent::Table(table) => {
+                table.body.push(row_of(element));
+                self.clone()
+            }
+            ElementContent::TablePart(part) if part.tag == Tag::Tr => {
+                part.elements.push(cell_of(element));
+                self.clone()
+            }
+            ElementContent::TablePart(part) => {
+                part.elements.push(element);
+                self.clone()
+            }
+            ElementContent::TableCell(cell) => {
+                cell.elements.push(element);
+                self.clone()
+            }
             _ => panic!("Tried to push to an unpushable element. {:?}", self),
         }
     }
@@ -62,20 +106,166 @@ impl Element {
     }
 
     pub fn add_hover_style(mut self, style: Style) -> Self {
-        self.meta.add_hover_style(style);
+        self.meta.add_state_styles(State::Hover, &[style]);
         self
     }
 
     pub fn add_hover_styles(mut self, styles: Vec<Style>) -> Self {
-        self.meta.add_hover_styles(styles);
+        self.meta.add_state_styles(State::Hover, &styles);
+        self
+    }
+
+    pub fn add_state_style(mut self, state: State, style: Style) -> Self {
+        self.meta.add_state_styles(state, &[style]);
+        self
+    }
+
+    // styles that only apply in a state such as State::Focus, or to a part of the element such as State::Before
+    pub fn add_state_styles(mut self, state: State, styles: &[Style]) -> Self {
+        self.meta.add_state_styles(state, styles);
+        self
+    }
+
+    // styles that replace the element's own ones on screens no wider than the breakpoint
+    pub fn add_styles_at(mut self, breakpoint: Breakpoint, styles: &[Style]) -> Self {
+        self.meta.add_styles_at(breakpoint, styles);
+        self
+    }
+
+    // styles every paragraph of a text element the same way
+    pub fn add_paragraph_styles(mut self, styles: &[Style]) -> Self {
+        match &mut self.content {
+            ElementContent::Text(text) => {
+                text.paragraph_styles.extend_from_slice(styles);
+                self
+            }
+            _ => panic!("Tried to add paragraph styles to an element without paragraphs. {:?}", self),
+        }
+    }
+
+    // writes a column, row or grid as a semantic element like Landmark::Nav or Landmark::Footer, its layout stays the same
+    pub fn with_tag(mut self, landmark: Landmark) -> Self {
+        match &mut self.content {
+            ElementContent::Column(Column { tag: container_tag, .. })
+            | ElementContent::Row(Row { tag: container_tag, .. })
+            | ElementContent::Grid(Grid { tag: container_tag, .. }) => {
+                *container_tag = landmark.tag();
+                self
+            }
+            _ => panic!("Tried to set the tag of an element that isn't a column, row or grid. {:?}", self),
+        }
+    }
+
+    // the number an ordered list counts from
+    pub fn starting_at(mut self, start: i32) -> Self {
+        match &self.content {
+            ElementContent::List(List { kind: ListKind::Ordered, .. }) => {
+                self.meta.attributes.insert("start".to_string(), start.to_string());
+                self
+            }
+            _ => panic!("Tried to set the start of an element that isn't an ordered list. {:?}", self),
+        }
+    }
+
+    // counts an ordered list down instead of up
+    pub fn reversed(mut self) -> Self {
+        match &self.content {
+            ElementContent::List(List { kind: ListKind::Ordered, .. }) => {
+                self.meta.attributes.insert("reversed".to_string(), String::new());
+                self
+            }
+            _ => panic!("Tried to reverse an element that isn't an ordered list. {:?}", self),
+        }
+    }
+
+    pub fn with_caption(mut self, caption: Element) -> Self {
+        match &mut self.content {
+            ElementContent::Table(table) => {
+                table.caption = Some(Box::new(
+                    Element::from(ElementContent::TablePart(TablePart::new(Tag::Caption))).push(caption),
+                ));
+                self
+            }
+            _ => panic!("Tried to add a caption to an element that isn't a table. {:?}", self),
+        }
+    }
+
+    pub fn push_header_row(mut self, row: Element) -> Self {
+        match &mut self.content {
+            ElementContent::Table(table) => {
+                table.head.push(row_of(row));
+                self
+            }
+            _ => panic!("Tried to push a header row to an element that isn't a table. {:?}", self),
+        }
+    }
+
+    pub fn push_footer_row(mut self, row: Element) -> Self {
+        match &mut self.content {
+            ElementContent::Table(table) => {
+                table.foot.push(row_of(row));
+                self
+            }
+            _ => panic!("Tried to push a footer row to an element that isn't a table. {:?}", self),
+        }
+    }
+
+    pub fn spanning_columns(self, columns: u32) -> Self {
+        self.with_cell_attribute("colspan", columns)
+    }
+
+    pub fn spanning_rows(self, rows: u32) -> Self {
+        self.with_cell_attribute("rowspan", rows)
+    }
+
+    fn with_cell_attribute(mut self, attribute: &str, value: u32) -> Self {
+        match &self.content {
+            ElementContent::TableCell(_) => {
+                self.meta.attributes.insert(attribute.to_string(), value.to_string());
+                self
+            }
+            _ => panic!("Tried to set the {attribute} of an element that isn't a table cell. {:?}", self),
+        }
+    }
+
+    pub fn with_id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
         self
     }
 
+    // used as the prefix of generated ids
+    pub fn kind(&self) -> String {
+        match &self.content {
+            ElementContent::Column(_) => "column".to_string(),
+            ElementContent::Row(_) => "row".to_string(),
+            ElementContent::Grid(_) => "grid".to_string(),
+            ElementContent::List(_) => "list".to_string(),
+            ElementContent::ListItem(item) => item.tag.to_string(),
+            ElementContent::Table(_) => "table".to_string(),
+            ElementContent::TablePart(part) => part.tag.to_string(),
+            ElementContent::TableCell(cell) => cell.tag.to_string(),
+            ElementContent::Text(_) => "text".to_string(),
+            ElementContent::Paragraph(_) => "p".to_string(),
+            ElementContent::Link(_) => "link".to_string(),
+            ElementContent::Heading(heading) => heading.level.to_string(),
+            ElementContent::Image(_) => "image".to_string(),
+            ElementContent::RawHtml(_) => "raw-html".to_string(),
+        }
+    }
+
     pub fn get_tag(&self)-> Tag{
         match &self.content{
-            ElementContent::Column(_) => Tag::Div,
-            ElementContent::Row(_) => Tag::Div,
-            ElementContent::Text(_) => Tag::Span,
+            ElementContent::Column(column) => column.tag,
+            ElementContent::Row(row) => row.tag,
+            ElementContent::Grid(grid) => grid.tag,
+            ElementContent::List(list) => list.kind.tag(),
+            ElementContent::ListItem(item) => item.tag,
+            ElementContent::Table(_) => Tag::Table,
+            ElementContent::TablePart(part) => part.tag,
+            ElementContent::TableCell(cell) => cell.tag,
+            ElementContent::Text(text) if text.is_inline() => Tag::Span,
+            ElementContent::Text(_) => Tag::Div,
+            ElementContent::Paragraph(_) => Tag::P,
             ElementContent::Link(_) => Tag::A,
             ElementContent::Heading(heading) => {
                 match heading.level {
@@ -88,6 +278,7 @@ impl Element {
                 }
             },
             ElementContent::Image(_) => Tag::IMG,
+            ElementContent::RawHtml(_) => Tag::Div,
         }
     }
 }
@@ -96,18 +287,27 @@ impl Element {
 pub enum ElementContent {
     Column(Column),
     Row(Row),
+    Grid(Grid),
+    List(List),
+    ListItem(ListItem),
+    Table(Table),
+    TablePart(TablePart),
+    TableCell(TableCell),
     Text(Text),
+    Paragraph(Paragraph),
     Link(Link),
     Heading(Heading),
-    Image(Image)
+    Image(Image),
+    RawHtml(RawHtml)
 }
 
 #[derive(Debug, Clone)]
 pub struct ElementMetaData {
     pub classes: Vec<String>,
     pub styles: Vec<Style>,
-    pub hover_styles: Vec<Style>,
-    pub attributes: HashMap<String, String>,
+    pub state_styles: BTreeMap<State, Vec<Style>>,
+    pub breakpoint_styles: BTreeMap<Breakpoint, Vec<Style>>,
+    pub attributes: BTreeMap<String, String>,
 }
 
 impl ElementMetaData {
@@ -115,8 +315,9 @@ impl ElementMetaData {
         Self {
             classes: Vec::new(),
             styles: Vec::new(),
-            hover_styles:Vec::new(),
-            attributes: HashMap::<String, String>::new(),
+            state_styles: BTreeMap::new(),
+            breakpoint_styles: BTreeMap::new(),
+            attributes: BTreeMap::<String, String>::new(),
         }
     }
 
@@ -131,16 +332,23 @@ impl ElementMetaData {
         self.styles.extend(styles.iter().map(|style|style.clone()).collect::<Vec<Style>>());
         self
     }
-    pub fn add_hover_style(&mut self, style: Style) -> &mut Self {
-        self.hover_styles.push(style);
+    pub fn add_state_styles(&mut self, state: State, styles: &[Style]) -> &mut Self {
+        self.state_styles.entry(state).or_default().extend_from_slice(styles);
         self
     }
 
-    // Add multiple styles at once
-    pub fn add_hover_styles(&mut self, styles: Vec<Style>) -> &mut Self {
-        self.hover_styles.extend(styles);
+    pub fn add_styles_at(&mut self, breakpoint: Breakpoint, styles: &[Style]) -> &mut Self {
+        self.breakpoint_styles.entry(breakpoint).or_default().extend_from_slice(styles);
         self
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+            && self.styles.is_empty()
+            && self.state_styles.is_empty()
+            && self.breakpoint_styles.is_empty()
+            && self.attributes.is_empty()
+    }
 }
 
 impl Default for ElementMetaData {
@@ -149,17 +357,98 @@ impl Default for ElementMetaData {
     }
 }
 
+// The pseudo-classes and pseudo-elements an element can be styled in. They are declared in the order their rules are
+// written, so that e.g. :active wins over :hover and :hover over :visited.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum State {
+    FirstChild,
+    LastChild,
+    // a formula such as "2n+1" or "odd"
+    NthChild(String),
+    Visited,
+    Hover,
+    Focus,
+    FocusVisible,
+    Active,
+    Placeholder,
+    // the generated content goes first, the styles then apply to it
+    Before(String),
+    After(String),
+}
+
+impl State {
+    pub fn selector(&self) -> String {
+        match self {
+            State::FirstChild => ":first-child".to_string(),
+            State::LastChild => ":last-child".to_string(),
+            State::NthChild(formula) => format!(":nth-child({formula})"),
+            State::Visited => ":visited".to_string(),
+            State::Hover => ":hover".to_string(),
+            State::Focus => ":focus".to_string(),
+            State::FocusVisible => ":focus-visible".to_string(),
+            State::Active => ":active".to_string(),
+            State::Placeholder => "::placeholder".to_string(),
+            State::Before(_) => "::before".to_string(),
+            State::After(_) => "::after".to_string(),
+        }
+    }
+
+    // ::before and ::after aren't drawn at all without a content declaration
+    pub fn content(&self) -> Option<&str> {
+        match self {
+            State::Before(content) | State::After(content) => Some(content),
+            _ => None,
+        }
+    }
+}
+
+// Desktop applies above the tablet width, Tablet and Mobile at or below their widths. They are declared from widest to
+// narrowest so that when a phone matches both Tablet and Mobile the Mobile styles win.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Breakpoint {
+    Desktop,
+    Tablet,
+    Mobile,
+}
+
+// the widths in pixels of each breakpoint, set on the site from the theme
+#[derive(Debug, Clone, Copy)]
+pub struct Breakpoints {
+    pub mobile: u32,
+    pub tablet: u32,
+}
+
+impl Breakpoints {
+    pub const fn new(mobile: u32, tablet: u32) -> Self {
+        Self { mobile, tablet }
+    }
+
+    pub fn media_query(&self, breakpoint: Breakpoint) -> String {
+        match breakpoint {
+            Breakpoint::Desktop => format!("(min-width:{}px)", self.tablet + 1),
+            Breakpoint::Tablet => format!("(max-width:{}px)", self.tablet),
+            Breakpoint::Mobile => format!("(max-width:{}px)", self.mobile),
+        }
+    }
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self::new(600, 1024)
+    }
+}
+
 #[derive(Clone, Debug)]
 pub enum Attribute {
     Height(u32),
     Width(u32),
 }
 
-impl Attribute {
-    fn to_string(&self) -> String {
+impl std::fmt::Display for Attribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Attribute::Height(px) => format!("height={}px", px),
-            Attribute::Width(px) => format!("width={}px", px),
+            Attribute::Height(px) => write!(f, "height={}px", px),
+            Attribute::Width(px) => write!(f, "width={}px", px),
         }
     }
 }
@@ -173,15 +462,20 @@ pub enum Style {
     Padding(Unit),
     PaddingEach(Sides),
     BackgroundColor(Color),
-    BackgroundImage(Image),
+    // layered, the first one is drawn on top
+    BackgroundImage(Vec<Background>),
     BackgroundSize(BackgroundSize),
+    // one for each layer of the background image, in the same order
+    BackgroundPosition(Vec<BackgroundPosition>),
+    BackgroundRepeat(Vec<BackgroundRepeat>),
     TextColor(Color),
     Center,
     Width(Unit),
     MaxWidth(Unit),
     Height(Unit),
     MaxHeight(Unit),
-    Font(&'static str),
+    // the family's files are registered on the site with Site::add_font
+    Font(FontFamily),
     FontWeight(FontWeight),
     FontSize(Unit),
     AlignItems(AlignItems),
@@ -190,16 +484,89 @@ pub enum Style {
     Row,
     NoUnderline,
     TextAlign(TextAlign),
-    LineHeight(Unit)
-    
+    LineHeight(Unit),
+    // the generated content of a ::before or ::after, see State::Before
+    Content(String),
+    Grid,
+    GridTemplateColumns(Vec<Track>),
+    GridTemplateRows(Vec<Track>),
+    // one string per row of the grid, naming the area each cell belongs to, e.g. vec!["header header", "nav main"]
+    GridTemplateAreas(Vec<&'static str>),
+    GridColumn(GridPlacement),
+    GridRow(GridPlacement),
+    GridArea(&'static str),
+    Gap(Unit),
+    JustifyItems(AlignItems),
+    Border(Border),
+    BorderTop(Border),
+    BorderRight(Border),
+    BorderBottom(Border),
+    BorderLeft(Border),
+    // drawn in order, the first one on top
+    BoxShadow(Vec<Shadow>),
+    Position(Position),
+    Top(Unit),
+    Right(Unit),
+    Bottom(Unit),
+    Left(Unit),
+    ZIndex(i32),
+    Opacity(f32),
+    Overflow(Overflow),
+    OverflowX(Overflow),
+    OverflowY(Overflow),
+    FlexWrap(FlexWrap),
+    FlexGrow(f32),
+    FlexShrink(f32),
+    FlexBasis(Unit),
+    MinWidth(Unit),
+    MinHeight(Unit),
+    Cursor(Cursor),
+    TextTransform(TextTransform),
+    LetterSpacing(Unit),
+    ObjectFit(ObjectFit),
+    ListMarker(ListMarker),
+    ListMarkerPosition(ListMarkerPosition),
+    // run together, e.g. one for the color and one for the background
+    Transition(Vec<Transition>),
+    // the keyframes it names have to be registered on the site with Site::add_keyframes
+    Animation(Animation),
+    // for anything there isn't a variant for yet, written as `property:value;`
+    Custom(String, String),
 }
 
 impl Style {
     pub fn variant_eq(&self, other: &Self) -> bool {
-        std::mem::discriminant(self) == std::mem::discriminant(other)
+        match (self, other) {
+            // custom styles only replace each other when they set the same property
+            (Self::Custom(property, _), Self::Custom(other_property, _)) => property == other_property,
+            _ => std::mem::discriminant(self) == std::mem::discriminant(other),
+        }
+    }
+
+    pub fn custom(property: &str, value: &str) -> Self {
+        Self::Custom(property.to_string(), value.to_string())
+    }
+
+    // background images are relative to the site, like routes, so they're put under its base path when written
+    pub fn under_base_path(self, base_href: &str) -> Self {
+        match self {
+            Self::BackgroundImage(layers) => Self::BackgroundImage(
+                layers
+                    .into_iter()
+                    .map(|layer| match layer {
+                        Background::Url(src) => Background::Url(under_base_path(base_href, &src)),
+                        layer => layer,
+                    })
+                    .collect(),
+            ),
+            style => style,
+        }
     }
-    pub fn to_string(&self) -> String {
-        match self{
+}
+
+impl std::fmt::Display for Style {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let css = match self{
             Self::Rounded(unit) => format!("border-radius:{unit};"),
             Self::RoundedEach(corners) => format!("border-top-left-radius:{};border-top-right-radius:{};border-bottom-left-radius:{};border-bottom-right-radius:{};", corners.top_left, corners.top_right, corners.bottom_left, corners.bottom_right),
             Self::Margin(unit) => format!("margin:{};", unit),
@@ -207,8 +574,10 @@ impl Style {
             Self::Padding(unit) => format!("padding:{unit};"),
             Self::PaddingEach(sides) => format!("padding-top:{};padding-bottom:{};padding-right:{};padding-left:{};", sides.top, sides.bottom, sides.right, sides.left),
             Self::BackgroundColor(color) => format!("background-color:{};", color),
-            Self::BackgroundImage(image) => format!("background-image:url({});", image.src),
+            Self::BackgroundImage(layers) => format!("background-image:{};", layers.iter().map(|layer| layer.to_string()).collect::<Vec<String>>().join(",")),
             Self::BackgroundSize(background_size)=> format!("background-size:{background_size};"),
+            Self::BackgroundPosition(positions) => format!("background-position:{};", positions.iter().map(|position| position.to_string()).collect::<Vec<String>>().join(",")),
+            Self::BackgroundRepeat(repeats) => format!("background-repeat:{};", repeats.iter().map(|repeat| repeat.to_string()).collect::<Vec<String>>().join(",")),
             Self::TextColor(color) => format!("color:{};", color),
             Self::Center => format!("margin:auto;"),
             Self::Height(unit) => format!("height:{unit};" ),
@@ -224,10 +593,52 @@ impl Style {
             Self::Row=> format!("display:flex;flex-flow:row nowrap;align-items:center;"),
             Self::NoUnderline => format!("text-decoration:none;"),
             Self::TextAlign(alignment)=> format!("text-align:{alignment};"),
-            Self::LineHeight(unit) => format!("line-height:{unit};")
+            Self::LineHeight(unit) => format!("line-height:{unit};"),
+            Self::Grid => "display:grid;".to_string(),
+            Self::GridTemplateColumns(tracks) => format!("grid-template-columns:{};", tracks_to_string(tracks)),
+            Self::GridTemplateRows(tracks) => format!("grid-template-rows:{};", tracks_to_string(tracks)),
+            Self::GridTemplateAreas(rows) => format!("grid-template-areas:{};", rows.iter().map(|row| format!("\"{row}\"")).collect::<Vec<String>>().join(" ")),
+            Self::GridColumn(placement) => format!("grid-column:{placement};"),
+            Self::GridRow(placement) => format!("grid-row:{placement};"),
+            Self::GridArea(area) => format!("grid-area:{area};"),
+            Self::Gap(unit) => format!("gap:{unit};"),
+            Self::JustifyItems(value) => format!("justify-items:{value};"),
+            Self::Border(border) => format!("border:{border};"),
+            Self::BorderTop(border) => format!("border-top:{border};"),
+            Self::BorderRight(border) => format!("border-right:{border};"),
+            Self::BorderBottom(border) => format!("border-bottom:{border};"),
+            Self::BorderLeft(border) => format!("border-left:{border};"),
+            Self::BoxShadow(shadows) => format!("box-shadow:{};", shadows.iter().map(|shadow| shadow.to_string()).collect::<Vec<String>>().join(",")),
+            Self::Position(position) => format!("position:{position};"),
+            Self::Top(unit) => format!("top:{unit};"),
+            Self::Right(unit) => format!("right:{unit};"),
+            Self::Bottom(unit) => format!("bottom:{unit};"),
+            Self::Left(unit) => format!("left:{unit};"),
+            Self::ZIndex(index) => format!("z-index:{index};"),
+            Self::Opacity(opacity) => format!("opacity:{opacity};"),
+            Self::Overflow(overflow) => format!("overflow:{overflow};"),
+            Self::OverflowX(overflow) => format!("overflow-x:{overflow};"),
+            Self::OverflowY(overflow) => format!("overflow-y:{overflow};"),
+            Self::FlexWrap(wrap) => format!("flex-wrap:{wrap};"),
+            Self::FlexGrow(grow) => format!("flex-grow:{grow};"),
+            Self::FlexShrink(shrink) => format!("flex-shrink:{shrink};"),
+            Self::FlexBasis(unit) => format!("flex-basis:{unit};"),
+            Self::MinWidth(unit) => format!("min-width:{unit};"),
+            Self::MinHeight(unit) => format!("min-height:{unit};"),
+            Self::Cursor(cursor) => format!("cursor:{cursor};"),
+            Self::TextTransform(transform) => format!("text-transform:{transform};"),
+            Self::LetterSpacing(unit) => format!("letter-spacing:{unit};"),
+            Self::ObjectFit(fit) => format!("object-fit:{fit};"),
+            Self::ListMarker(marker) => format!("list-style-type:{marker};"),
+            Self::ListMarkerPosition(position) => format!("list-style-position:{position};"),
+            Self::Transition(transitions) => format!("transition:{};", transitions.iter().map(|transition| transition.to_string()).collect::<Vec<String>>().join(",")),
+            Self::Animation(animation) => format!("animation:{animation};"),
+            Self::Custom(property, value) => format!("{property}:{value};"),
+            Self::Content(content) => format!("content:\"{}\";", content.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\A ")),
 
             
-        }
+        };
+        write!(f, "{css}")
     }
 }
 
@@ -315,6 +726,8 @@ pub struct Color {
     blue: u8,
     green: u8,
     alpha: f32,
+    // set for theme colours, which are written as var(--color-<token>)
+    token: Option<&'static str>,
 }
 impl Color {
     pub const fn new(red: u8, green: u8, blue: u8, alpha: f32) -> Self {
@@ -323,25 +736,238 @@ impl Color {
             green,
             blue,
             alpha,
+            token: None,
         }
     }
 
-    // get rid of the following if you end up not using utility classses
-    pub fn to_utility_string(&self) -> String {
+    // makes this colour a theme token, so a theme registered on the site can change it. Colours derived from it with
+    // lighten, darken, mix or with_alpha are plain colours again.
+    pub const fn as_token(self, token: &'static str) -> Self {
+        Self {
+            token: Some(token),
+            ..self
+        }
+    }
+
+    pub const fn token(&self) -> Option<&'static str> {
+        self.token
+    }
+
+    // the colour's own value, even for a theme token
+    pub fn rgba(&self) -> String {
         format!(
-            "r{}-g{}-b{}-a{}",
+            "rgba({},{},{},{})",
             self.red, self.green, self.blue, self.alpha
         )
     }
+
+    // hue in degrees, saturation and lightness from 0 to 1
+    pub const fn hsl(hue: f32, saturation: f32, lightness: f32, alpha: f32) -> Self {
+        let saturation = clamp_unit(saturation);
+        let lightness = clamp_unit(lightness);
+        let hue = hue % 360.0;
+        let hue = if hue < 0.0 { hue + 360.0 } else { hue } / 360.0;
+        if saturation == 0.0 {
+            let grey = to_channel(lightness);
+            return Self::new(grey, grey, grey, alpha);
+        }
+        let q = if lightness < 0.5 {
+            lightness * (1.0 + saturation)
+        } else {
+            lightness + saturation - lightness * saturation
+        };
+        let p = 2.0 * lightness - q;
+        Self::new(
+            to_channel(hue_to_rgb(p, q, hue + 1.0 / 3.0)),
+            to_channel(hue_to_rgb(p, q, hue)),
+            to_channel(hue_to_rgb(p, q, hue - 1.0 / 3.0)),
+            alpha,
+        )
+    }
+
+    // the hue in degrees, saturation and lightness from 0 to 1
+    pub const fn to_hsl(self) -> (f32, f32, f32) {
+        let red = self.red as f32 / 255.0;
+        let green = self.green as f32 / 255.0;
+        let blue = self.blue as f32 / 255.0;
+        let max = max(red, max(green, blue));
+        let min = min(red, min(green, blue));
+        let lightness = (max + min) / 2.0;
+        if max == min {
+            return (0.0, 0.0, lightness);
+        }
+        let delta = max - min;
+        let saturation = if lightness > 0.5 {
+            delta / (2.0 - max - min)
+        } else {
+            delta / (max + min)
+        };
+        let hue = if max == red {
+            (green - blue) / delta + if green < blue { 6.0 } else { 0.0 }
+        } else if max == green {
+            (blue - red) / delta + 2.0
+        } else {
+            (red - green) / delta + 4.0
+        };
+        (hue * 60.0, saturation, lightness)
+    }
+
+    // amount is added to the lightness, so 0.1 is ten percent lighter
+    pub const fn lighten(&self, amount: f32) -> Self {
+        let (hue, saturation, lightness) = self.to_hsl();
+        Self::hsl(hue, saturation, lightness + amount, self.alpha)
+    }
+
+    pub const fn darken(&self, amount: f32) -> Self {
+        self.lighten(-amount)
+    }
+
+    // weight is how much of the other colour ends up in the mix, from 0 to 1
+    pub const fn mix(&self, other: &Color, weight: f32) -> Self {
+        let weight = clamp_unit(weight);
+        Self::new(
+            blend(self.red, other.red, weight),
+            blend(self.green, other.green, weight),
+            blend(self.blue, other.blue, weight),
+            self.alpha * (1.0 - weight) + other.alpha * weight,
+        )
+    }
+
+    pub const fn with_alpha(&self, alpha: f32) -> Self {
+        Self::new(self.red, self.green, self.blue, alpha)
+    }
+
+    // as defined by WCAG, from 0 for black to 1 for white
+    pub fn relative_luminance(&self) -> f32 {
+        let linear = |channel: u8| {
+            let channel = channel as f32 / 255.0;
+            if channel <= 0.03928 {
+                channel / 12.92
+            } else {
+                ((channel + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.red) + 0.7152 * linear(self.green) + 0.0722 * linear(self.blue)
+    }
+
+    // from 1 to 21, WCAG asks for at least 4.5 for body text and 3 for large text
+    pub fn contrast_ratio(&self, other: &Color) -> f32 {
+        let (lighter, darker) = {
+            let (a, b) = (self.relative_luminance(), other.relative_luminance());
+            if a > b { (a, b) } else { (b, a) }
+        };
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    // accepts #rgb, #rgba, #rrggbb, #rrggbbaa, hsl(210, 50%, 40%) and hsla(210, 50%, 40%, 0.5)
+    pub fn parse(value: &str) -> Result<Self, ColorParseError> {
+        let value = value.trim();
+        let error = || ColorParseError(value.to_string());
+        if let Some(hex) = value.strip_prefix('#') {
+            let digits = hex
+                .chars()
+                .map(|digit| digit.to_digit(16).map(|digit| digit as u8))
+                .collect::<Option<Vec<u8>>>()
+                .ok_or_else(error)?;
+            let channels = match digits.len() {
+                3 | 4 => digits.iter().map(|digit| digit * 17).collect::<Vec<u8>>(),
+                6 | 8 => digits.chunks(2).map(|pair| pair[0] * 16 + pair[1]).collect::<Vec<u8>>(),
+                _ => return Err(error()),
+            };
+            let alpha = channels.get(3).map(|alpha| *alpha as f32 / 255.0).unwrap_or(1.0);
+            return Ok(Self::new(channels[0], channels[1], channels[2], alpha));
+        }
+        let arguments = value
+            .strip_prefix("hsla(")
+            .or_else(|| value.strip_prefix("hsl("))
+            .and_then(|arguments| arguments.strip_suffix(')'))
+            .ok_or_else(error)?;
+        let numbers = arguments
+            .split([',', ' ', '/'])
+            .filter(|argument| !argument.is_empty())
+            .map(|argument| match argument.strip_suffix('%') {
+                Some(percent) => percent.parse::<f32>().map(|percent| percent / 100.0),
+                None => argument.trim_end_matches("deg").parse::<f32>(),
+            })
+            .collect::<Result<Vec<f32>, _>>()
+            .map_err(|_| error())?;
+        match numbers[..] {
+            [hue, saturation, lightness] => Ok(Self::hsl(hue, saturation, lightness, 1.0)),
+            [hue, saturation, lightness, alpha] => Ok(Self::hsl(hue, saturation, lightness, alpha)),
+            _ => Err(error()),
+        }
+    }
+}
+
+impl std::str::FromStr for Color {
+    type Err = ColorParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value)
+    }
+}
+
+#[derive(Debug)]
+pub struct ColorParseError(pub String);
+
+impl std::fmt::Display for ColorParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "'{}' is not a hex or hsl colour", self.0)
+    }
+}
+
+const fn clamp_unit(value: f32) -> f32 {
+    if value < 0.0 {
+        0.0
+    } else if value > 1.0 {
+        1.0
+    } else {
+        value
+    }
+}
+
+const fn max(a: f32, b: f32) -> f32 {
+    if a > b { a } else { b }
+}
+
+const fn min(a: f32, b: f32) -> f32 {
+    if a < b { a } else { b }
+}
+
+const fn blend(from: u8, to: u8, weight: f32) -> u8 {
+    to_channel((from as f32 * (1.0 - weight) + to as f32 * weight) / 255.0)
+}
+
+// rounds a 0 to 1 value to the nearest 0 to 255 channel
+const fn to_channel(value: f32) -> u8 {
+    (clamp_unit(value) * 255.0 + 0.5) as u8
+}
+
+const fn hue_to_rgb(p: f32, q: f32, t: f32) -> f32 {
+    let t = if t < 0.0 {
+        t + 1.0
+    } else if t > 1.0 {
+        t - 1.0
+    } else {
+        t
+    };
+    if t < 1.0 / 6.0 {
+        p + (q - p) * 6.0 * t
+    } else if t < 1.0 / 2.0 {
+        q
+    } else if t < 2.0 / 3.0 {
+        p + (q - p) * (2.0 / 3.0 - t) * 6.0
+    } else {
+        p
+    }
 }
 
 impl std::fmt::Display for Color {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(
-            f,
-            "rgba({},{},{},{})",
-            self.red, self.green, self.blue, self.alpha
-        )
+        match self.token {
+            Some(token) => write!(f, "var(--color-{token})"),
+            None => write!(f, "{}", self.rgba()),
+        }
     }
 }
 
@@ -431,94 +1057,1038 @@ impl std::fmt::Display for BackgroundSize {
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct Row {
-    pub elements: Vec<Element>,
+// one layer of an element's background
+#[derive(Clone, Debug)]
+pub enum Background {
+    Url(String),
+    LinearGradient(LinearGradient),
+    RadialGradient(RadialGradient),
+    ConicGradient(ConicGradient),
 }
 
-impl Row {
-    pub fn new() -> Element {
-        let mut meta = ElementMetaData::new();
-        meta.add_style(Style::Row);
-        Element {
-            id: format!("row-{}",id::generate()),
-            content: ElementContent::Row(Self {
-                elements: Vec::new(),
-            }),
-            meta,
-        }
+impl Background {
+    pub fn url(src: &str) -> Self {
+        Self::Url(src.to_string())
     }
 
-    pub fn push(mut self, element: impl Into<Element>) -> Self {
-        self.elements.push(element.into());
-        self
+    // 0 degrees points up and 90 to the right, so 180.0 fades from the top of the element to the bottom
+    pub fn linear(angle_deg: f32, stops: Vec<ColorStop>) -> Self {
+        Self::LinearGradient(LinearGradient { angle_deg, stops })
     }
-}
 
-#[derive(Debug, Clone)]
-pub struct Column {
-    pub elements: Vec<Element>,
+    pub fn radial(shape: RadialShape, at: BackgroundPosition, stops: Vec<ColorStop>) -> Self {
+        Self::RadialGradient(RadialGradient { shape, at, stops })
+    }
+
+    pub fn conic(from_deg: f32, at: BackgroundPosition, stops: Vec<ColorStop>) -> Self {
+        Self::ConicGradient(ConicGradient { from_deg, at, stops })
+    }
 }
 
-impl Column {
-    pub fn new() -> Element {
-        let mut meta = ElementMetaData::new();
-        meta.add_style(Style::Column);
-        Element {
-            id: format!("column-{}", id::generate()),
-            content: ElementContent::Column(Self {
-                elements: Vec::new(),
-            }),
-            meta,
+impl std::fmt::Display for Background {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Background::Url(src) => write!(f, "url(\"{}\")", src.replace('\\', "\\\\").replace('"', "\\\"")),
+            Background::LinearGradient(gradient) => {
+                write!(f, "linear-gradient({}deg,{})", gradient.angle_deg, stops_to_string(&gradient.stops))
+            }
+            Background::RadialGradient(gradient) => write!(
+                f,
+                "radial-gradient({} at {},{})",
+                gradient.shape,
+                gradient.at,
+                stops_to_string(&gradient.stops)
+            ),
+            Background::ConicGradient(gradient) => write!(
+                f,
+                "conic-gradient(from {}deg at {},{})",
+                gradient.from_deg,
+                gradient.at,
+                stops_to_string(&gradient.stops)
+            ),
         }
     }
+}
 
-    pub fn push(mut self, element: impl Into<Element>) -> Self {
-        self.elements.push(element.into());
-        self
-    }
+#[derive(Clone, Debug)]
+pub struct LinearGradient {
+    pub angle_deg: f32,
+    pub stops: Vec<ColorStop>,
 }
 
+#[derive(Clone, Debug)]
+pub struct RadialGradient {
+    pub shape: RadialShape,
+    pub at: BackgroundPosition,
+    pub stops: Vec<ColorStop>,
+}
 
-#[derive(Debug, Clone)]
-pub struct Text {
-    pub content: Vec<String>,
+#[derive(Clone, Debug)]
+pub struct ConicGradient {
+    pub from_deg: f32,
+    pub at: BackgroundPosition,
+    pub stops: Vec<ColorStop>,
 }
 
-impl Text {
-    pub fn new(content: Vec<String>) -> Element {
-        Element {
-            id: format!("text-{}", id::generate()),
-            content: ElementContent::Text(Self {
-                content,
-            }),
-            meta: ElementMetaData::new(),
-        }
+// without a position the stops are spread evenly between their neighbours
+#[derive(Clone, Debug)]
+pub struct ColorStop {
+    pub color: Color,
+    pub position: Option<Unit>,
+}
+
+impl ColorStop {
+    pub const fn new(color: Color) -> Self {
+        Self { color, position: None }
+    }
+
+    pub const fn at(color: Color, position: Unit) -> Self {
+        Self { color, position: Some(position) }
     }
 }
 
-impl El for Text {
-    fn to_html(&self) -> String {
-        // inserts the text content into a span if there is only one item, or into paragraphs if there are multiple
-        if self.content.len() == 1 {
-        format!(
-            "<span {{attributes}} class=~~classes~~ style=~~styles~~>{}</span>",
-            self.content[0]
-        )
-        .to_string()} else {
-            self.content.iter().map(|paragraph|{
-                format!("<p {{attributes}} class=~~classes~~ style=~~styles~~>{paragraph}</p>")
-            })
-            .collect::<Vec<String>>()
-            .join("\n")
+impl std::fmt::Display for ColorStop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.position {
+            Some(position) => write!(f, "{} {}", self.color, position),
+            None => write!(f, "{}", self.color),
         }
     }
 }
 
-#[derive(Debug, Clone)]
-pub struct Button {
-    label: Element,
-    on_press: String, //change this to a Message
+fn stops_to_string(stops: &[ColorStop]) -> String {
+    stops.iter().map(|stop| stop.to_string()).collect::<Vec<String>>().join(",")
+}
+
+#[derive(Clone, Debug)]
+pub enum RadialShape {
+    Circle,
+    Ellipse,
+}
+
+impl std::fmt::Display for RadialShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RadialShape::Circle => write!(f, "circle"),
+            RadialShape::Ellipse => write!(f, "ellipse"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum BackgroundPosition {
+    Center,
+    Top,
+    Bottom,
+    Left,
+    Right,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    // from the left and from the top
+    At(Unit, Unit),
+}
+
+impl std::fmt::Display for BackgroundPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackgroundPosition::Center => write!(f, "center"),
+            BackgroundPosition::Top => write!(f, "top"),
+            BackgroundPosition::Bottom => write!(f, "bottom"),
+            BackgroundPosition::Left => write!(f, "left"),
+            BackgroundPosition::Right => write!(f, "right"),
+            BackgroundPosition::TopLeft => write!(f, "top left"),
+            BackgroundPosition::TopRight => write!(f, "top right"),
+            BackgroundPosition::BottomLeft => write!(f, "bottom left"),
+            BackgroundPosition::BottomRight => write!(f, "bottom right"),
+            BackgroundPosition::At(x, y) => write!(f, "{x} {y}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum BackgroundRepeat {
+    Repeat,
+    NoRepeat,
+    RepeatX,
+    RepeatY,
+    Space,
+    Round,
+}
+
+impl std::fmt::Display for BackgroundRepeat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let variant = match self {
+            BackgroundRepeat::Repeat => "repeat",
+            BackgroundRepeat::NoRepeat => "no-repeat",
+            BackgroundRepeat::RepeatX => "repeat-x",
+            BackgroundRepeat::RepeatY => "repeat-y",
+            BackgroundRepeat::Space => "space",
+            BackgroundRepeat::Round => "round",
+        };
+        write!(f, "{}", variant)
+    }
+}
+
+// how a property eases to its new value, e.g. Transition::new("color", 200) for hover colours
+#[derive(Clone, Debug)]
+pub struct Transition {
+    pub property: &'static str,
+    pub duration_ms: u32,
+    pub easing: Easing,
+    pub delay_ms: u32,
+}
+
+impl Transition {
+    pub const fn new(property: &'static str, duration_ms: u32) -> Self {
+        Self { property, duration_ms, easing: Easing::Ease, delay_ms: 0 }
+    }
+
+    pub const fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub const fn delay(mut self, delay_ms: u32) -> Self {
+        self.delay_ms = delay_ms;
+        self
+    }
+}
+
+impl std::fmt::Display for Transition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}ms {} {}ms", self.property, self.duration_ms, self.easing, self.delay_ms)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Easing {
+    Linear,
+    Ease,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    CubicBezier(f32, f32, f32, f32),
+    Steps(u32),
+}
+
+impl std::fmt::Display for Easing {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Easing::Linear => write!(f, "linear"),
+            Easing::Ease => write!(f, "ease"),
+            Easing::EaseIn => write!(f, "ease-in"),
+            Easing::EaseOut => write!(f, "ease-out"),
+            Easing::EaseInOut => write!(f, "ease-in-out"),
+            Easing::CubicBezier(x1, y1, x2, y2) => write!(f, "cubic-bezier({x1},{y1},{x2},{y2})"),
+            Easing::Steps(steps) => write!(f, "steps({steps})"),
+        }
+    }
+}
+
+// A named set of keyframes, registered once on the site and written to its stylesheet once however many elements use
+// it. Offsets are percentages of the animation, e.g.
+// Keyframes::new("fade-in").at(0.0, &[Style::Opacity(0.0)]).at(100.0, &[Style::Opacity(1.0)])
+#[derive(Clone, Debug)]
+pub struct Keyframes {
+    pub name: &'static str,
+    pub frames: Vec<(f32, Vec<Style>)>,
+}
+
+impl Keyframes {
+    pub fn new(name: &'static str) -> Self {
+        Self { name, frames: Vec::new() }
+    }
+
+    pub fn at(mut self, percent: f32, styles: &[Style]) -> Self {
+        self.frames.push((percent, styles.to_vec()));
+        self
+    }
+
+    // an animation that plays these keyframes once
+    pub fn animation(&self, duration_ms: u32) -> Animation {
+        Animation::new(self.name, duration_ms)
+    }
+
+    pub fn to_css(&self) -> String {
+        let frames = self
+            .frames
+            .iter()
+            .map(|(percent, styles)| format!("{percent}%{{{}}}", styles.iter().map(|style| style.to_string()).collect::<String>()))
+            .collect::<String>();
+        format!("@keyframes {}{{{frames}}}", self.name)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Animation {
+    pub name: &'static str,
+    pub duration_ms: u32,
+    pub easing: Easing,
+    pub delay_ms: u32,
+    pub iterations: Iterations,
+    pub direction: AnimationDirection,
+    pub fill_mode: FillMode,
+}
+
+impl Animation {
+    pub const fn new(name: &'static str, duration_ms: u32) -> Self {
+        Self {
+            name,
+            duration_ms,
+            easing: Easing::Ease,
+            delay_ms: 0,
+            iterations: Iterations::Count(1),
+            direction: AnimationDirection::Normal,
+            fill_mode: FillMode::None,
+        }
+    }
+
+    pub const fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub const fn delay(mut self, delay_ms: u32) -> Self {
+        self.delay_ms = delay_ms;
+        self
+    }
+
+    pub const fn iterations(mut self, iterations: Iterations) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    pub const fn direction(mut self, direction: AnimationDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    pub const fn fill_mode(mut self, fill_mode: FillMode) -> Self {
+        self.fill_mode = fill_mode;
+        self
+    }
+}
+
+impl std::fmt::Display for Animation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}ms {} {}ms {} {} {}",
+            self.name, self.duration_ms, self.easing, self.delay_ms, self.iterations, self.direction, self.fill_mode
+        )
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Iterations {
+    Count(u32),
+    Infinite,
+}
+
+impl std::fmt::Display for Iterations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Iterations::Count(count) => write!(f, "{count}"),
+            Iterations::Infinite => write!(f, "infinite"),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum AnimationDirection {
+    Normal,
+    Reverse,
+    Alternate,
+    AlternateReverse,
+}
+
+impl std::fmt::Display for AnimationDirection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let variant = match self {
+            AnimationDirection::Normal => "normal",
+            AnimationDirection::Reverse => "reverse",
+            AnimationDirection::Alternate => "alternate",
+            AnimationDirection::AlternateReverse => "alternate-reverse",
+        };
+        write!(f, "{}", variant)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum FillMode {
+    None,
+    Forwards,
+    Backwards,
+    Both,
+}
+
+impl std::fmt::Display for FillMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let variant = match self {
+            FillMode::None => "none",
+            FillMode::Forwards => "forwards",
+            FillMode::Backwards => "backwards",
+            FillMode::Both => "both",
+        };
+        write!(f, "{}", variant)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Border {
+    pub width: Unit,
+    pub style: BorderStyle,
+    pub color: Color,
+}
+
+impl Border {
+    pub const fn new(width: Unit, style: BorderStyle, color: Color) -> Self {
+        Self { width, style, color }
+    }
+}
+
+impl std::fmt::Display for Border {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} {}", self.width, self.style, self.color)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum BorderStyle {
+    None,
+    Solid,
+    Dashed,
+    Dotted,
+    Double,
+}
+
+impl std::fmt::Display for BorderStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let variant = match self {
+            BorderStyle::None => "none",
+            BorderStyle::Solid => "solid",
+            BorderStyle::Dashed => "dashed",
+            BorderStyle::Dotted => "dotted",
+            BorderStyle::Double => "double",
+        };
+        write!(f, "{}", variant)
+    }
+}
+
+// offsets, blur and spread are in pixels, offsets can be negative to cast the shadow up or to the left
+#[derive(Clone, Debug)]
+pub struct Shadow {
+    pub x: i32,
+    pub y: i32,
+    pub blur: u32,
+    pub spread: i32,
+    pub color: Color,
+    pub inset: bool,
+}
+
+impl Shadow {
+    pub const fn new(x: i32, y: i32, blur: u32, color: Color) -> Self {
+        Self { x, y, blur, spread: 0, color, inset: false }
+    }
+
+    pub const fn spread(mut self, spread: i32) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    pub const fn inset(mut self) -> Self {
+        self.inset = true;
+        self
+    }
+}
+
+impl std::fmt::Display for Shadow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.inset {
+            write!(f, "inset ")?;
+        }
+        write!(f, "{}px {}px {}px {}px {}", self.x, self.y, self.blur, self.spread, self.color)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Position {
+    Static,
+    Relative,
+    Absolute,
+    Fixed,
+    Sticky,
+}
+
+impl std::fmt::Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let variant = match self {
+            Position::Static => "static",
+            Position::Relative => "relative",
+            Position::Absolute => "absolute",
+            Position::Fixed => "fixed",
+            Position::Sticky => "sticky",
+        };
+        write!(f, "{}", variant)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Overflow {
+    Visible,
+    Hidden,
+    Clip,
+    Scroll,
+    Auto,
+}
+
+impl std::fmt::Display for Overflow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let variant = match self {
+            Overflow::Visible => "visible",
+            Overflow::Hidden => "hidden",
+            Overflow::Clip => "clip",
+            Overflow::Scroll => "scroll",
+            Overflow::Auto => "auto",
+        };
+        write!(f, "{}", variant)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum FlexWrap {
+    NoWrap,
+    Wrap,
+    WrapReverse,
+}
+
+impl std::fmt::Display for FlexWrap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let variant = match self {
+            FlexWrap::NoWrap => "nowrap",
+            FlexWrap::Wrap => "wrap",
+            FlexWrap::WrapReverse => "wrap-reverse",
+        };
+        write!(f, "{}", variant)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Cursor {
+    Auto,
+    Default,
+    Pointer,
+    Text,
+    Move,
+    Grab,
+    Grabbing,
+    Help,
+    Wait,
+    NotAllowed,
+}
+
+impl std::fmt::Display for Cursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let variant = match self {
+            Cursor::Auto => "auto",
+            Cursor::Default => "default",
+            Cursor::Pointer => "pointer",
+            Cursor::Text => "text",
+            Cursor::Move => "move",
+            Cursor::Grab => "grab",
+            Cursor::Grabbing => "grabbing",
+            Cursor::Help => "help",
+            Cursor::Wait => "wait",
+            Cursor::NotAllowed => "not-allowed",
+        };
+        write!(f, "{}", variant)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum TextTransform {
+    None,
+    Uppercase,
+    Lowercase,
+    Capitalize,
+}
+
+impl std::fmt::Display for TextTransform {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let variant = match self {
+            TextTransform::None => "none",
+            TextTransform::Uppercase => "uppercase",
+            TextTransform::Lowercase => "lowercase",
+            TextTransform::Capitalize => "capitalize",
+        };
+        write!(f, "{}", variant)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ObjectFit {
+    Fill,
+    Contain,
+    Cover,
+    None,
+    ScaleDown,
+}
+
+impl std::fmt::Display for ObjectFit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let variant = match self {
+            ObjectFit::Fill => "fill",
+            ObjectFit::Contain => "contain",
+            ObjectFit::Cover => "cover",
+            ObjectFit::None => "none",
+            ObjectFit::ScaleDown => "scale-down",
+        };
+        write!(f, "{}", variant)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ListMarker {
+    None,
+    Disc,
+    Circle,
+    Square,
+    Decimal,
+    DecimalLeadingZero,
+    LowerAlpha,
+    UpperAlpha,
+    LowerRoman,
+    UpperRoman,
+    // any text, e.g. "→ "
+    Text(&'static str),
+}
+
+impl std::fmt::Display for ListMarker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let variant = match self {
+            ListMarker::None => "none",
+            ListMarker::Disc => "disc",
+            ListMarker::Circle => "circle",
+            ListMarker::Square => "square",
+            ListMarker::Decimal => "decimal",
+            ListMarker::DecimalLeadingZero => "decimal-leading-zero",
+            ListMarker::LowerAlpha => "lower-alpha",
+            ListMarker::UpperAlpha => "upper-alpha",
+            ListMarker::LowerRoman => "lower-roman",
+            ListMarker::UpperRoman => "upper-roman",
+            ListMarker::Text(text) => {
+                return write!(f, "\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+            }
+        };
+        write!(f, "{}", variant)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum ListMarkerPosition {
+    Inside,
+    Outside,
+}
+
+impl std::fmt::Display for ListMarkerPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ListMarkerPosition::Inside => write!(f, "inside"),
+            ListMarkerPosition::Outside => write!(f, "outside"),
+        }
+    }
+}
+
+// the sections of a page a column, row or grid can be written as, see Element::with_tag
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Landmark {
+    Header,
+    Nav,
+    Main,
+    Section,
+    Article,
+    Footer,
+    Aside,
+}
+
+impl Landmark {
+    fn tag(&self) -> Tag {
+        match self {
+            Landmark::Header => Tag::Header,
+            Landmark::Nav => Tag::Nav,
+            Landmark::Main => Tag::Main,
+            Landmark::Section => Tag::Section,
+            Landmark::Article => Tag::Article,
+            Landmark::Footer => Tag::Footer,
+            Landmark::Aside => Tag::Aside,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Row {
+    pub elements: Vec<Element>,
+    pub tag: Tag,
+}
+
+impl Row {
+    pub fn new() -> Element {
+        let mut meta = ElementMetaData::new();
+        meta.add_style(Style::Row);
+        Element {
+            id: None,
+            content: ElementContent::Row(Self {
+                elements: Vec::new(),
+                tag: Tag::Div,
+            }),
+            meta,
+        }
+    }
+
+    pub fn push(mut self, element: impl Into<Element>) -> Self {
+        self.elements.push(element.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Column {
+    pub elements: Vec<Element>,
+    pub tag: Tag,
+}
+
+impl Column {
+    pub fn new() -> Element {
+        let mut meta = ElementMetaData::new();
+        meta.add_style(Style::Column);
+        Element {
+            id: None,
+            content: ElementContent::Column(Self {
+                elements: Vec::new(),
+                tag: Tag::Div,
+            }),
+            meta,
+        }
+    }
+
+    pub fn push(mut self, element: impl Into<Element>) -> Self {
+        self.elements.push(element.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Grid {
+    pub elements: Vec<Element>,
+    pub tag: Tag,
+}
+
+impl Grid {
+    pub fn new() -> Self {
+        Self {
+            elements: Vec::new(),
+            tag: Tag::Div,
+        }
+    }
+}
+
+// Anything pushed to a list becomes one of its items, so a list pushed to a list is nested in an item of its own.
+// Description lists take terms and definitions, anything else pushed to one is wrapped in a definition.
+#[derive(Debug, Clone)]
+pub struct List {
+    pub kind: ListKind,
+    pub elements: Vec<Element>,
+}
+
+impl List {
+    pub fn new(kind: ListKind) -> Self {
+        Self {
+            kind,
+            elements: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListKind {
+    Unordered,
+    Ordered,
+    Description,
+}
+
+impl ListKind {
+    fn tag(&self) -> Tag {
+        match self {
+            ListKind::Unordered => Tag::Ul,
+            ListKind::Ordered => Tag::Ol,
+            ListKind::Description => Tag::Dl,
+        }
+    }
+
+    // the element as an item of this kind of list, wrapping it when it isn't one already
+    fn item(&self, element: Element) -> Element {
+        let tag = match &element.content {
+            ElementContent::ListItem(item) => Some(item.tag),
+            _ => None,
+        };
+        match (self, tag) {
+            (ListKind::Description, Some(Tag::Dt | Tag::Dd)) => element,
+            (ListKind::Description, _) => definition().push(element),
+            (_, Some(Tag::Li)) => element,
+            _ => list_item().push(element),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ListItem {
+    pub elements: Vec<Element>,
+    // Li, or Dt and Dd in a description list
+    pub tag: Tag,
+}
+
+impl ListItem {
+    pub fn new(tag: Tag) -> Self {
+        Self {
+            elements: Vec::new(),
+            tag,
+        }
+    }
+}
+
+// Rows pushed to a table go in its body, anything pushed to a table that isn't a row is put in one, and anything pushed
+// to a row that isn't a cell is put in one.
+#[derive(Debug, Clone)]
+pub struct Table {
+    pub caption: Option<Box<Element>>,
+    pub head: Vec<Element>,
+    pub body: Vec<Element>,
+    pub foot: Vec<Element>,
+}
+
+impl Table {
+    pub fn new() -> Self {
+        Self {
+            caption: None,
+            head: Vec::new(),
+            body: Vec::new(),
+            foot: Vec::new(),
+        }
+    }
+
+    // the caption and each section that has rows, in the order they're written in
+    pub fn parts(&self) -> Vec<Element> {
+        let sections = [(Tag::Thead, &self.head), (Tag::Tbody, &self.body), (Tag::Tfoot, &self.foot)]
+            .into_iter()
+            .filter(|(_, rows)| !rows.is_empty())
+            .map(|(tag, rows)| {
+                let mut section = TablePart::new(tag);
+                section.elements = rows.clone();
+                ElementContent::TablePart(section).into()
+            });
+        self.caption.iter().map(|caption| *caption.clone()).chain(sections).collect()
+    }
+}
+
+// the caption, sections and rows of a table
+#[derive(Debug, Clone)]
+pub struct TablePart {
+    pub elements: Vec<Element>,
+    pub tag: Tag,
+}
+
+impl TablePart {
+    pub fn new(tag: Tag) -> Self {
+        Self {
+            elements: Vec::new(),
+            tag,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TableCell {
+    pub elements: Vec<Element>,
+    // Td, or Th for header cells
+    pub tag: Tag,
+}
+
+impl TableCell {
+    pub fn new(tag: Tag) -> Self {
+        Self {
+            elements: Vec::new(),
+            tag,
+        }
+    }
+}
+
+// tables have no <colgroup> and a single <tbody>, so a header only ever labels its column or its row
+#[derive(Debug, Clone)]
+pub enum Scope {
+    Column,
+    Row,
+}
+
+impl std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Scope::Column => write!(f, "col"),
+            Scope::Row => write!(f, "row"),
+        }
+    }
+}
+
+// a column of table_of, e.g. TableColumn::new("Name", |skill: &Skill| text(&skill.name))
+pub struct TableColumn<T> {
+    pub title: &'static str,
+    pub cell: Box<dyn Fn(&T) -> Element>,
+}
+
+impl<T> TableColumn<T> {
+    pub fn new(title: &'static str, cell: impl Fn(&T) -> Element + 'static) -> Self {
+        Self {
+            title,
+            cell: Box::new(cell),
+        }
+    }
+}
+
+// the size of a column or row in a grid's template
+#[derive(Debug, Clone)]
+pub enum Track {
+    Length(Unit),
+    Fr(f32),
+    Auto,
+    MinContent,
+    MaxContent,
+    MinMax(Box<Track>, Box<Track>),
+    Repeat(u32, Vec<Track>),
+    // as many columns as fit, empty ones collapse with AutoFit and are kept with AutoFill
+    AutoFit(Box<Track>),
+    AutoFill(Box<Track>),
+}
+
+impl Track {
+    pub fn minmax(min: Track, max: Track) -> Self {
+        Self::MinMax(Box::new(min), Box::new(max))
+    }
+
+    pub fn auto_fit(track: Track) -> Self {
+        Self::AutoFit(Box::new(track))
+    }
+
+    pub fn auto_fill(track: Track) -> Self {
+        Self::AutoFill(Box::new(track))
+    }
+}
+
+impl std::fmt::Display for Track {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Track::Length(unit) => write!(f, "{unit}"),
+            Track::Fr(fr) => write!(f, "{fr}fr"),
+            Track::Auto => write!(f, "auto"),
+            Track::MinContent => write!(f, "min-content"),
+            Track::MaxContent => write!(f, "max-content"),
+            Track::MinMax(min, max) => write!(f, "minmax({min},{max})"),
+            Track::Repeat(count, tracks) => write!(f, "repeat({count},{})", tracks_to_string(tracks)),
+            Track::AutoFit(track) => write!(f, "repeat(auto-fit,{track})"),
+            Track::AutoFill(track) => write!(f, "repeat(auto-fill,{track})"),
+        }
+    }
+}
+
+fn tracks_to_string(tracks: &[Track]) -> String {
+    tracks.iter().map(|track| track.to_string()).collect::<Vec<String>>().join(" ")
+}
+
+// where a child sits in its grid, lines are numbered from 1 and negative lines count from the end
+#[derive(Debug, Clone)]
+pub enum GridPlacement {
+    Span(u32),
+    Line(i32),
+    Lines(i32, i32),
+    // starts at the line and spans the given number of tracks
+    LineSpan(i32, u32),
+}
+
+impl std::fmt::Display for GridPlacement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GridPlacement::Span(span) => write!(f, "span {span}"),
+            GridPlacement::Line(line) => write!(f, "{line}"),
+            GridPlacement::Lines(start, end) => write!(f, "{start}/{end}"),
+            GridPlacement::LineSpan(start, span) => write!(f, "{start}/span {span}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Text {
+    // each one is a Paragraph element, so they can be styled and given ids of their own
+    pub paragraphs: Vec<Element>,
+    // added to every paragraph, after the paragraph's own styles
+    pub paragraph_styles: Vec<Style>,
+}
+
+impl Text {
+    pub fn new(paragraphs: Vec<Element>) -> Self {
+        Self {
+            paragraphs,
+            paragraph_styles: Vec::new(),
+        }
+    }
+
+    // a single plain paragraph is written straight into a span, anything else becomes a div of <p> elements
+    pub fn is_inline(&self) -> bool {
+        match self.paragraphs.as_slice() {
+            [paragraph] => {
+                matches!(paragraph.content, ElementContent::Paragraph(_))
+                    && paragraph.id.is_none()
+                    && paragraph.meta.is_empty()
+                    && self.paragraph_styles.is_empty()
+            }
+            _ => false,
+        }
+    }
+}
+
+impl El for Text {
+    fn to_html(&self) -> String {
+        let paragraphs = self.paragraphs.iter().filter_map(|paragraph| match &paragraph.content {
+            ElementContent::Paragraph(paragraph) => Some(escape_text(&paragraph.content)),
+            _ => None,
+        });
+        if self.is_inline() {
+            format!("<span>{}</span>", paragraphs.collect::<String>())
+        } else {
+            paragraphs
+                .map(|paragraph| format!("<p>{paragraph}</p>"))
+                .collect::<Vec<String>>()
+                .join("\n")
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Paragraph {
+    pub content: String,
+}
+
+impl Paragraph {
+    pub fn new(content: &str) -> Self {
+        Self {
+            content: content.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Button {
+    label: Element,
+    on_press: String, //change this to a Message
 }
 
 
@@ -533,7 +2103,7 @@ impl Link {
         let mut meta = ElementMetaData::new();
         meta.attributes.insert("href".to_string(), target.to_string());
         Element {
-            id: format!("link-{}", id::generate()),
+            id: None,
             content: ElementContent::Link(Self {
                 label: Box::new(label),
                 target: target.to_string(),
@@ -555,6 +2125,134 @@ pub enum Unit {
     Em(f32),
     Rem(f32),
     Percent(f32),
+    // percentages of the viewport, Dvh follows the visible height as mobile browser bars show and hide
+    Vw(f32),
+    Vh(f32),
+    Dvh(f32),
+    // the width of the font's "0"
+    Ch(f32),
+    // e.g. a width or margin left to the browser, fractions of a grid's free space are Track::Fr as they're only valid
+    // in grid templates
+    Auto,
+    // the sizes of the site's theme, e.g. Unit::Space("m") is var(--space-m)
+    Space(&'static str),
+    FontSize(&'static str),
+    Radius(&'static str),
+    // built with +, -, * and /, e.g. Unit::Percent(100.0) - Unit::Px(40) is calc(100% - 40px)
+    Calc(Box<Unit>, Operator, Operand),
+    Clamp(Box<Unit>, Box<Unit>, Box<Unit>),
+    // the first operand and the rest, so there's always at least one
+    Min(Box<Unit>, Vec<Unit>),
+    Max(Box<Unit>, Vec<Unit>),
+}
+
+impl Unit {
+    // the preferred size, kept between min and max
+    pub fn clamp(min: Unit, preferred: Unit, max: Unit) -> Self {
+        Self::Clamp(Box::new(min), Box::new(preferred), Box::new(max))
+    }
+
+    pub fn min(first: Unit, rest: Vec<Unit>) -> Self {
+        Self::Min(Box::new(first), rest)
+    }
+
+    pub fn max(first: Unit, rest: Vec<Unit>) -> Self {
+        Self::Max(Box::new(first), rest)
+    }
+
+    // how the unit is written inside another math function, where calc() isn't needed and nested sums need brackets
+    fn expression(&self) -> String {
+        match self {
+            Unit::Calc(left, operator, right) => format!("{} {operator} {right}", left.operand()),
+            unit => unit.to_string(),
+        }
+    }
+
+    fn operand(&self) -> String {
+        match self {
+            Unit::Calc(..) => format!("({})", self.expression()),
+            unit => unit.to_string(),
+        }
+    }
+}
+
+// The right hand side of a calc(), a unit for + and - or a plain number for * and /. The number can only be built by
+// multiplying or dividing, so it can't end up as a length on its own.
+#[derive(Debug, Clone)]
+pub struct Operand(OperandValue);
+
+#[derive(Debug, Clone)]
+enum OperandValue {
+    Unit(Box<Unit>),
+    Number(f32),
+}
+
+impl std::fmt::Display for Operand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.0 {
+            OperandValue::Unit(unit) => write!(f, "{}", unit.operand()),
+            OperandValue::Number(number) => write!(f, "{number}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl std::fmt::Display for Operator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operator::Add => write!(f, "+"),
+            Operator::Subtract => write!(f, "-"),
+            Operator::Multiply => write!(f, "*"),
+            Operator::Divide => write!(f, "/"),
+        }
+    }
+}
+
+impl std::ops::Add for Unit {
+    type Output = Unit;
+
+    fn add(self, other: Unit) -> Unit {
+        Unit::Calc(Box::new(self), Operator::Add, Operand(OperandValue::Unit(Box::new(other))))
+    }
+}
+
+impl std::ops::Sub for Unit {
+    type Output = Unit;
+
+    fn sub(self, other: Unit) -> Unit {
+        Unit::Calc(Box::new(self), Operator::Subtract, Operand(OperandValue::Unit(Box::new(other))))
+    }
+}
+
+impl std::ops::Mul<f32> for Unit {
+    type Output = Unit;
+
+    fn mul(self, factor: f32) -> Unit {
+        Unit::Calc(Box::new(self), Operator::Multiply, Operand(OperandValue::Number(factor)))
+    }
+}
+
+impl std::ops::Div<f32> for Unit {
+    type Output = Unit;
+
+    fn div(self, divisor: f32) -> Unit {
+        Unit::Calc(Box::new(self), Operator::Divide, Operand(OperandValue::Number(divisor)))
+    }
+}
+
+fn expressions_to_string(first: &Unit, rest: &[Unit]) -> String {
+    std::iter::once(first)
+        .chain(rest)
+        .map(|unit| unit.expression())
+        .collect::<Vec<String>>()
+        .join(",")
 }
 
 impl std::fmt::Display for Unit {
@@ -564,6 +2262,24 @@ impl std::fmt::Display for Unit {
             Unit::Em(em) => write!(f, "{}em", em),
             Unit::Rem(rem) => write!(f, "{}rem", rem),
             Unit::Percent(percent) => write!(f, "{}%", percent),
+            Unit::Vw(vw) => write!(f, "{vw}vw"),
+            Unit::Vh(vh) => write!(f, "{vh}vh"),
+            Unit::Dvh(dvh) => write!(f, "{dvh}dvh"),
+            Unit::Ch(ch) => write!(f, "{ch}ch"),
+            Unit::Auto => write!(f, "auto"),
+            Unit::Space(token) => write!(f, "var(--space-{token})"),
+            Unit::FontSize(token) => write!(f, "var(--font-size-{token})"),
+            Unit::Radius(token) => write!(f, "var(--radius-{token})"),
+            Unit::Calc(..) => write!(f, "calc({})", self.expression()),
+            Unit::Clamp(min, preferred, max) => write!(
+                f,
+                "clamp({},{},{})",
+                min.expression(),
+                preferred.expression(),
+                max.expression()
+            ),
+            Unit::Min(first, rest) => write!(f, "min({})", expressions_to_string(first, rest)),
+            Unit::Max(first, rest) => write!(f, "max({})", expressions_to_string(first, rest)),
         }
     }
 }
@@ -577,7 +2293,7 @@ pub struct Heading {
 impl Heading {
     pub fn new(level: HeadingLevel, text: &str) -> Element {
         Element {
-            id: format!("{}-{}",level, id::generate()),
+            id: None,
             content: ElementContent::Heading(Heading {
                 level,
                 content: text.to_string(),
@@ -607,7 +2323,7 @@ impl Image {
         meta.attributes.insert("src".to_string(), src.to_string());
         meta.attributes.insert("alt".to_string(), alt.to_string());
         Element {
-            id: format!("image-{}", id::generate()),
+            id: None,
             content: ElementContent::Image(Self{
                 src:src.to_string(),
                 alt:alt.to_string()
@@ -622,6 +2338,20 @@ impl El for Image{
         format!("<img src='{}' alt='{}'/>", &self.src, &self.alt)
     }
 }
+// markup that is written to the page without being escaped, only use it for html you trust
+#[derive(Debug, Clone)]
+pub struct RawHtml {
+    pub html: String,
+}
+
+impl RawHtml {
+    pub fn new(html: &str) -> Self {
+        Self {
+            html: html.to_string(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum HeadingLevel {
     H1,
@@ -652,20 +2382,194 @@ pub fn row() -> Element {
     Row::new()
 }
 
+pub fn grid(columns: Vec<Track>) -> Element {
+    Element::from(ElementContent::Grid(Grid::new()))
+        .add_styles(&[Style::Grid, Style::GridTemplateColumns(columns)])
+}
+
+pub fn list() -> Element {
+    ElementContent::List(List::new(ListKind::Unordered)).into()
+}
+
+pub fn ordered_list() -> Element {
+    ElementContent::List(List::new(ListKind::Ordered)).into()
+}
+
+pub fn description_list() -> Element {
+    ElementContent::List(List::new(ListKind::Description)).into()
+}
+
+pub fn list_item() -> Element {
+    ElementContent::ListItem(ListItem::new(Tag::Li)).into()
+}
+
+pub fn term(label: &str) -> Element {
+    Element::from(ElementContent::ListItem(ListItem::new(Tag::Dt))).push(text(label))
+}
+
+pub fn definition() -> Element {
+    ElementContent::ListItem(ListItem::new(Tag::Dd)).into()
+}
+
+pub fn table() -> Element {
+    ElementContent::Table(Table::new()).into()
+}
+
+pub fn table_row() -> Element {
+    ElementContent::TablePart(TablePart::new(Tag::Tr)).into()
+}
+
+pub fn cell() -> Element {
+    ElementContent::TableCell(TableCell::new(Tag::Td)).into()
+}
+
+// scope tells screen readers which cells the header labels
+pub fn header_cell(scope: Scope) -> Element {
+    let mut cell = Element::from(ElementContent::TableCell(TableCell::new(Tag::Th)));
+    cell.meta.attributes.insert("scope".to_string(), scope.to_string());
+    cell
+}
+
+// a table with a row for each item, and a header row with the title of each column
+pub fn table_of<T>(items: &[T], columns: &[TableColumn<T>]) -> Element {
+    let header = columns.iter().fold(table_row(), |mut row, column| {
+        row.push(header_cell(Scope::Column).push(text(column.title)))
+    });
+    items.iter().fold(table().push_header_row(header), |mut table, item| {
+        table.push(columns.iter().fold(table_row(), |mut row, column| {
+            row.push((column.cell)(item))
+        }))
+    })
+}
+
+fn row_of(element: Element) -> Element {
+    match &element.content {
+        ElementContent::TablePart(part) if part.tag == Tag::Tr => element,
+        _ => table_row().push(element),
+    }
+}
+
+fn cell_of(element: Element) -> Element {
+    match &element.content {
+        ElementContent::TableCell(_) => element,
+        _ => cell().push(element),
+    }
+}
+
+// paragraphs are separated by a blank line
 pub fn text(text: &str) -> Element {
-    let text:Vec<String> =
-        text.split("\n\n").into_iter().map(|paragraph|paragraph.to_string()).collect();
-    Text::new(text)
+    let paragraphs: Vec<Element> = text.split("\n\n").map(paragraph).collect();
+    ElementContent::Text(Text::new(paragraphs)).into()
+}
+
+pub fn paragraph(text: &str) -> Element {
+    ElementContent::Paragraph(Paragraph::new(text)).into()
+}
+
+// for text made of paragraphs that each need their own styles, classes or ids, text elements are split into their
+// paragraphs and anything else is written as it is between them
+pub fn paragraphs(paragraphs: Vec<Element>) -> Element {
+    ElementContent::Text(Text::new(
+        paragraphs
+            .into_iter()
+            .flat_map(|element| match element.content {
+                ElementContent::Text(text) if element.id.is_none() && element.meta.is_empty() => text
+                    .paragraphs
+                    .into_iter()
+                    .map(|paragraph| paragraph.add_styles(&text.paragraph_styles))
+                    .collect(),
+                _ => vec![element],
+            })
+            .collect(),
+    ))
+    .into()
 }
 
 pub fn heading(level: HeadingLevel, text: &str) -> Element {
     Heading::new(level, text)
 }
 
-pub fn link(label: Element, path: &str) -> Element {
-    Link::new(label, path)
+// the target can be a raw url or a route::Route, which renders as the route's href
+pub fn link(label: Element, target: impl ToString) -> Element {
+    Link::new(label, &target.to_string())
 }
 
 pub fn image(src:&str, alt:&str) -> Element {
     Image::new(src, alt)
-}
\ No newline at end of file
+}
+
+pub fn raw_html(html: &str) -> Element {
+    ElementContent::RawHtml(RawHtml::new(html)).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn channels(color: Color) -> (u8, u8, u8, f32) {
+        (color.red, color.green, color.blue, color.alpha)
+    }
+
+    #[test]
+    fn writes_math_functions() {
+        assert_eq!(Unit::min(Unit::Px(10), vec![]).to_string(), "min(10px)");
+        assert_eq!(Unit::max(Unit::Px(10), vec![Unit::Vw(50.0)]).to_string(), "max(10px,50vw)");
+        assert_eq!((Unit::Percent(100.0) - Unit::Px(40)).to_string(), "calc(100% - 40px)");
+        assert_eq!((Unit::Px(10) - (Unit::Px(4) + Unit::Px(2))).to_string(), "calc(10px - (4px + 2px))");
+        assert_eq!(Style::Margin(Unit::Auto).to_string(), "margin:auto;");
+        assert_eq!(
+            Unit::clamp(Unit::Rem(1.0), (Unit::Rem(1.0) + Unit::Vw(2.0)) * 2.0, Unit::Rem(3.0)).to_string(),
+            "clamp(1rem,(1rem + 2vw) * 2,3rem)"
+        );
+    }
+
+    #[test]
+    fn parses_every_hex_form() {
+        assert_eq!(channels(Color::parse("#f80").unwrap()), (255, 136, 0, 1.0));
+        assert_eq!(channels(Color::parse("#f80f").unwrap()), (255, 136, 0, 1.0));
+        assert_eq!(channels(Color::parse("#ff8800").unwrap()), (255, 136, 0, 1.0));
+        assert_eq!(channels(Color::parse("#FF880000").unwrap()), (255, 136, 0, 0.0));
+        let (_, _, _, alpha) = channels(Color::parse("#ff880080").unwrap());
+        assert!((alpha - 128.0 / 255.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn parses_hsl_and_hsla() {
+        assert_eq!(channels(Color::parse("hsl(0, 100%, 50%)").unwrap()), (255, 0, 0, 1.0));
+        assert_eq!(channels(Color::parse("hsl(240deg 100% 50%)").unwrap()), (0, 0, 255, 1.0));
+        assert_eq!(channels(Color::parse("hsl(0, 0%, 100%)").unwrap()), (255, 255, 255, 1.0));
+        assert_eq!(channels(Color::parse("hsla(120, 100%, 50%, 0.5)").unwrap()), (0, 255, 0, 0.5));
+        assert_eq!(channels(" hsl(120 100% 50% / 0.25) ".parse::<Color>().unwrap()), (0, 255, 0, 0.25));
+    }
+
+    #[test]
+    fn rejects_malformed_colours() {
+        for value in ["#ggg", "#12345", "hsl(1,2)", "", "#", "red", "hsl(1, 2%, 3%", "hsl(a, 2%, 3%)"] {
+            assert!(Color::parse(value).is_err(), "{value} should not parse");
+        }
+        assert_eq!(
+            Color::parse("#ggg").unwrap_err().to_string(),
+            "'#ggg' is not a hex or hsl colour"
+        );
+    }
+
+    #[test]
+    fn converts_to_hsl_and_back() {
+        let red = Color::new(255, 0, 0, 1.0);
+        assert_eq!(red.to_hsl(), (0.0, 1.0, 0.5));
+        let (hue, saturation, lightness) = Color::new(3, 102, 214, 1.0).to_hsl();
+        assert_eq!(channels(Color::hsl(hue, saturation, lightness, 1.0)), (3, 102, 214, 1.0));
+        assert_eq!(channels(red.lighten(0.5)), (255, 255, 255, 1.0));
+    }
+
+    #[test]
+    fn contrast_ratios_match_wcag() {
+        let black = Color::new(0, 0, 0, 1.0);
+        let white = Color::new(255, 255, 255, 1.0);
+        assert!((black.contrast_ratio(&white) - 21.0).abs() < 0.01);
+        assert!((white.contrast_ratio(&black) - 21.0).abs() < 0.01);
+        assert!((white.contrast_ratio(&white) - 1.0).abs() < 0.01);
+        // the lightest grey that fails AA on white
+        assert!((Color::new(119, 119, 119, 1.0).contrast_ratio(&white) - 4.48).abs() < 0.01);
+    }
+}